@@ -0,0 +1,57 @@
+//! Generates the in-process solution registry for the main binary.
+//! Every `src/bin/YYYY-DD.rs` is included as a module when the `registry` feature is enabled.
+use std::{env, fs, path::Path};
+
+/// Parses a solution file name like `2024-08.rs` into its year and day.
+fn parse_file_name(name: &str) -> Option<(u16, u8)> {
+    let (year, day) = name.strip_suffix(".rs")?.split_once('-')?;
+    let year = year.parse::<u16>().ok()?;
+    let day = day.parse::<u8>().ok()?;
+    (year >= 2015 && (1..=25).contains(&day)).then_some((year, day))
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    let mut puzzles: Vec<(u16, u8)> = vec![];
+
+    if env::var_os("CARGO_FEATURE_REGISTRY").is_some() {
+        let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+        if let Ok(entries) = fs::read_dir(bin_dir) {
+            puzzles = entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| parse_file_name(&entry.file_name().into_string().ok()?))
+                .collect();
+        }
+        puzzles.sort_unstable();
+    }
+
+    let mut registry = String::from("use advent_of_code::template::registry::Solution;\n\n");
+
+    for (year, day) in &puzzles {
+        let path = Path::new(&manifest_dir)
+            .join("src")
+            .join("bin")
+            .join(format!("{year}-{day:02}.rs"));
+        registry.push_str(&format!(
+            "#[path = {:?}]\nmod day_{year}_{day:02};\n",
+            path.display().to_string()
+        ));
+    }
+
+    registry.push_str(
+        "\n/// All solutions linked into this binary.\npub static SOLUTIONS: &[Solution] = &[\n",
+    );
+    for (year, day) in &puzzles {
+        registry.push_str(&format!(
+            "    Solution {{ puzzle: advent_of_code::template::Puzzle::new(advent_of_code::year!({year}), advent_of_code::day!({day})), run: day_{year}_{day:02}::__run_day }},\n"
+        ));
+    }
+    registry.push_str("];\n");
+
+    fs::write(Path::new(&out_dir).join("registry.rs"), registry).unwrap();
+}
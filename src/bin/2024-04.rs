@@ -1,5 +1,6 @@
 advent_of_code::solution!(4);
 
+use advent_of_code::utils::map::Neighbourhood;
 use itertools::{iproduct, Itertools};
 
 #[derive(Debug, PartialEq, Copy, Clone)]
@@ -105,24 +106,6 @@ fn to_letter<'a> (index: &(i32, i32), grid: &'a dyn Grid<Letter>) -> Option<Lett
     grid.get(index.0 as usize, index.1 as usize).copied()
 }
 
-const NEIGHBOR_OFFSETS: [(i32, i32); 8] = [
-    (-1, -1),
-    (-1, 0),
-    (-1, 1),
-    (0, -1),
-    (0, 1),
-    (1, -1),
-    (1, 0),
-    (1, 1),
-];
-
-const DIAG_OFFSETS: [(i32, i32); 4] = [
-    (-1, -1),
-    (-1, 1),
-    (1, -1),
-    (1, 1),
-];
-
 const XMAS: [Letter; 4] = [
     Letter::X,
     Letter::M,
@@ -139,7 +122,8 @@ pub fn part_one(input: &str) -> Option<u32> {
 
     let possibilities = indices
         .flat_map(move |index| {
-            NEIGHBOR_OFFSETS
+            Neighbourhood::Moore
+                .offsets()
                 .iter()
                 .map(move |&direction| indices_in_direction(&index, &direction, 3))
                 .filter(|word| word.iter().all(|i| valid_index(i, &grid)))
@@ -178,7 +162,8 @@ pub fn part_two(input: &str) -> Option<u32> {
         indices
             .filter(|index| to_letter(&(index.0 as i32, index.1 as i32), &grid) == Some(Letter::A))
             .map(move |index| {
-                DIAG_OFFSETS
+                Neighbourhood::Diagonal
+                    .offsets()
                     .iter()
                     .map(move |&direction| indices_in_direction(&index, &direction, 1).remove(1))
                     .collect::<Vec<_>>()
@@ -197,13 +182,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(18));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(9));
     }
 }
@@ -1,14 +1,8 @@
 advent_of_code::solution!(6);
 use rayon::prelude::*;
 
-
-#[derive(Debug, Clone, Copy, PartialEq)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
+use advent_of_code::utils::map::Direction;
+use advent_of_code::utils::vector::Vector;
 
 #[derive(Debug, Clone)]
 struct Map {
@@ -22,12 +16,7 @@ struct Map {
 
 impl Map {
     fn turn_right(&mut self) {
-        self.orientation = match self.orientation {
-            Direction::North => Direction::East,
-            Direction::East => Direction::South,
-            Direction::South => Direction::West,
-            Direction::West => Direction::North,
-        };
+        self.orientation = self.orientation.turn_one(false);
     }
 
     fn in_loop(&self) -> bool {
@@ -40,34 +29,26 @@ impl Map {
             return None;
         }
 
-        let (x, y) = (self.position.0 as i32, self.position.1 as i32);
-        self.visited.push((self.position.0, self.position.1));
-        self.path.push((self.position.0, self.position.1, self.orientation));
-
+        let (row, column) = self.position;
+        self.visited.push(self.position);
+        self.path.push((row, column, self.orientation));
 
-        let next_position = match self.orientation {
-            Direction::North => (x - 1, y),
-            Direction::East => (x, y + 1),
-            Direction::South => (x + 1, y),
-            Direction::West => (x, y - 1),
-        };
-
-        let valid = next_position.0 >= 0 && next_position.0 < self.dimensions.0 as i32 &&
-                    next_position.1 >= 0 && next_position.1 < self.dimensions.1 as i32;
 
+        let next_position = (Vector::new(column.into(), row.into()) + Vector::from(self.orientation))
+            .to_position(self.dimensions.1 as usize, self.dimensions.0 as usize);
 
-        if !valid {
+        let Some(next_position) = next_position else {
             self.path.pop();
             return None;
-        }
+        };
 
-        if self.content[next_position.0 as usize][next_position.1 as usize] == '#' {
+        if self.content[next_position.y as usize][next_position.x as usize] == '#' {
             self.turn_right();
             return Some(self.position);
         }
 
-        self.position = (next_position.0 as u32, next_position.1 as u32);
-        Some((next_position.0 as u32, next_position.1 as u32))
+        self.position = (next_position.y, next_position.x);
+        Some(self.position)
     }
 
 }
@@ -96,7 +77,7 @@ impl TryFrom<&str> for Map {
         Ok(Map {
             dimensions,
             position: location.ok_or(())?,
-            orientation: Direction::North,
+            orientation: Direction::Up,
             content,
             visited: Vec::new(),
             path: Vec::new(),
@@ -146,13 +127,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(41));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(6));
     }
 }
@@ -1,6 +1,7 @@
 use itertools::Itertools;
 
 advent_of_code::solution!(7);
+use advent_of_code::template::SolutionError;
 use rayon::prelude::*;
 
 fn potential_results(numbers: &Vec<u64>, value: Option<u64>, goal: u64, with_concat: bool) -> Option<Vec<u64>> {
@@ -43,38 +44,43 @@ fn potential_results(numbers: &Vec<u64>, value: Option<u64>, goal: u64, with_con
     Some(result)
 }
 
-fn try_parse(input: &str) -> Result<Vec<(u64, Vec<u64>)>, ()> {
+fn try_parse(input: &str) -> Result<Vec<(u64, Vec<u64>)>, SolutionError> {
+    // errors point at the token that failed to parse, tokens are slices of the input.
+    let error = |message: String, token: &str| {
+        SolutionError::new(message).at_offset(input, token.as_ptr() as usize - input.as_ptr() as usize)
+    };
+
     input
         .lines()
         .map(|line| {
-            let s = line.split(':').collect::<Vec<&str>>();
-            let result = s.get(0).ok_or(())?.parse::<u64>().map_err(|_| ())?;
-            let numbers = s.get(1).ok_or(())?.trim().split(' ').map(|n| n.parse::<u64>()).collect::<Result<Vec<u64>, _>>().map_err(|_| ())?;
+            let (value, numbers) = line.split_once(':').ok_or_else(|| error("missing `:`".into(), &line[line.len()..]))?;
+            let result = value.parse::<u64>().map_err(|e| error(e.to_string(), value))?;
+            let numbers = numbers.trim().split(' ').map(|n| n.parse::<u64>().map_err(|e| error(e.to_string(), n))).collect::<Result<Vec<u64>, _>>()?;
 
             Ok((result, numbers))
-        }).collect::<Result<Vec<(u64, Vec<u64>)>, ()>>()
+        }).collect::<Result<Vec<(u64, Vec<u64>)>, SolutionError>>()
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let parsed = try_parse(input).ok()?;
+pub fn part_one(input: &str) -> Result<u64, SolutionError> {
+    let parsed = try_parse(input)?;
 
     let result: u64 = parsed
         .par_iter()
         .map(|(result, numbers)| if potential_results(numbers, None, *result, false).unwrap_or(vec![]).contains(result) { *result } else { 0 })
         .sum();
 
-    Some(result)
+    Ok(result)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let parsed = try_parse(input).ok()?;
+pub fn part_two(input: &str) -> Result<u64, SolutionError> {
+    let parsed = try_parse(input)?;
 
     let result: u64 = parsed
         .par_iter()
         .map(|(result, numbers)| if potential_results(numbers, None, *result, true).unwrap_or(vec![]).contains(result) { *result } else { 0 })
         .sum();
 
-    Some(result)
+    Ok(result)
 }
 
 #[cfg(test)]
@@ -83,13 +89,19 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3749));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(3749));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(11387));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(11387));
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let error = try_parse("190: 10 19\n83: 17 x5\n").unwrap_err();
+        assert_eq!(error.to_string(), "invalid digit found in string (line 2, column 8)");
     }
 }
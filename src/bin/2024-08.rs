@@ -1,13 +1,11 @@
 use itertools::{iproduct, Itertools};
 
-advent_of_code::solution!(8);
+use advent_of_code::utils::vector::Point;
 
-fn translation(index: (usize, usize), other: (usize, usize)) -> (i32, i32) {
-    (index.0 as i32 - other.0 as i32, index.1 as i32 - other.1 as i32)
-}
+advent_of_code::solution!(8);
 
-fn valid(index: (i32, i32), dimension: (usize, usize)) -> bool {
-    index.0 >= 0 && index.0 < dimension.0 as i32 && index.1 >= 0 && index.1 < dimension.1 as i32
+fn point(index: (usize, usize)) -> Point {
+    Point::new(index.0 as i64, index.1 as i64)
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
@@ -22,21 +20,15 @@ pub fn part_one(input: &str) -> Option<u32> {
         .map(|frequency| iproduct!(0..x, 0..y).filter(|(i, j)| antenna_map[*i][*j] == *frequency).collect_vec())
         .flat_map(|frequency_locations| frequency_locations.into_iter().combinations(2))
         .flat_map(|combination| {
-            let translation_1 = translation(combination[0], combination[1]);
-            let translation_2 = translation(combination[1], combination[0]);
-            let anti_node_1 = (combination[0].0 as i32 + translation_1.0, combination[0].1 as i32 + translation_1.1);
-            let anti_node_2 = (combination[1].0 as i32 + translation_2.0, combination[1].1 as i32 + translation_2.1);
-
-            let mut anti_nodes = Vec::new();
-            if valid(anti_node_1, (x, y)) {
-                anti_nodes.push((anti_node_1.0 as u32, anti_node_1.1 as u32));
-            }
-
-            if valid(anti_node_2, (x, y)) {
-                anti_nodes.push((anti_node_2.0 as u32, anti_node_2.1 as u32));
-            }
-
-            anti_nodes
+            let (one, other) = (point(combination[0]), point(combination[1]));
+            let anti_node_1 = one + (one - other);
+            let anti_node_2 = other + (other - one);
+
+            [anti_node_1, anti_node_2]
+                .into_iter()
+                .filter_map(|anti_node| anti_node.to_position(x, y))
+                .map(|position| (position.x, position.y))
+                .collect_vec()
         })
         .collect_vec();
 
@@ -59,20 +51,14 @@ pub fn part_two(input: &str) -> Option<u32> {
         .map(|frequency| iproduct!(0..x, 0..y).filter(|(i, j)| antenna_map[*i][*j] == *frequency).collect_vec())
         .flat_map(|frequency_locations| frequency_locations.into_iter().combinations(2))
         .flat_map(|combination| {
-            let translation_1 = translation(combination[0], combination[1]);
-            let translation_2 = translation(combination[1], combination[0]);
-            let mut anti_node_1 = (combination[0].0 as i32, combination[0].1 as i32);
-            let mut anti_node_2 = (combination[1].0 as i32, combination[1].1 as i32);
+            let (one, other) = (point(combination[0]), point(combination[1]));
 
             let mut anti_nodes = Vec::new();
-            while valid(anti_node_1, (x, y)) {
-                anti_nodes.push((anti_node_1.0 as u32, anti_node_1.1 as u32));
-                anti_node_1 = (anti_node_1.0 + translation_1.0, anti_node_1.1 + translation_1.1);
-            }
-
-            while valid(anti_node_2, (x, y)) {
-                anti_nodes.push((anti_node_2.0 as u32, anti_node_2.1 as u32));
-                anti_node_2 = (anti_node_2.0 + translation_2.0, anti_node_2.1 + translation_2.1);
+            for (mut anti_node, translation) in [(one, one - other), (other, other - one)] {
+                while let Some(position) = anti_node.to_position(x, y) {
+                    anti_nodes.push((position.x, position.y));
+                    anti_node += translation;
+                }
             }
 
             anti_nodes
@@ -91,13 +77,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(14));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(34));
     }
 }
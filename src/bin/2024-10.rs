@@ -0,0 +1,68 @@
+advent_of_code::solution!(10);
+
+use advent_of_code::utils::grid::Grid;
+use advent_of_code::utils::map::{parse_grid, Map, Neighbourhood, Position};
+use advent_of_code::utils::search::{bfs, SearchResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Height(Option<u32>);
+
+impl TryFrom<char> for Height {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Height(None)),
+            _ => value.to_digit(10).map(|height| Height(Some(height))).ok_or(()),
+        }
+    }
+}
+
+// sums `score` over every pair of trailhead and reachable summit
+fn score_trails(input: &str, score: impl Fn(&SearchResult<Position>, &Position) -> u64) -> Option<u32> {
+    let grid: Grid<Height> = parse_grid(input).ok()?;
+    let height = |position: &Position| grid.get(position).and_then(|height| height.0);
+
+    let summits = grid.positions().filter(|position| height(position) == Some(9)).collect::<Vec<_>>();
+
+    let total = grid
+        .positions()
+        .filter(|position| height(position) == Some(0))
+        .map(|trailhead| {
+            let trails = bfs([trailhead], |position| {
+                let next = height(position).map(|height| height + 1);
+                grid.neighbours_in(position, Neighbourhood::VonNeumann)
+                    .filter(move |neighbour| next.is_some() && height(neighbour) == next)
+            });
+
+            summits.iter().map(|summit| score(&trails, summit)).sum::<u64>()
+        })
+        .sum::<u64>();
+
+    Some(total as u32)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    score_trails(input, |trails, summit| trails.distance(summit).is_some() as u64)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    score_trails(input, |trails, summit| trails.count_paths_to(summit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(36));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(81));
+    }
+}
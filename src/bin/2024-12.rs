@@ -9,6 +9,8 @@ use ascent_byods_rels::trrel_uf;
 
 use itertools::Itertools;
 
+use advent_of_code::utils::map::Neighbourhood;
+
 
 #[derive(Debug, Clone, Copy, PartialEq, Hash, Eq, PartialOrd, Ord)]
 struct Position {
@@ -16,24 +18,10 @@ struct Position {
     column: i32
 }
 
-fn adjacent_position(position: &Position, other: &Position) -> bool {
-    let up = position.row > 0 &&      position.row - 1 == other.row  && position.column == other.column;
-    let down =                        position.row + 1 == other.row  && position.column == other.column;
-    let left = position.column > 0 && position.row == other.row      && position.column - 1 == other.column;
-    let right =                       position.row == other.row      && position.column + 1 == other.column;
-
-    up || down || left || right
+fn offset(position: &Position, other: &Position) -> (i32, i32) {
+    (other.column - position.column, other.row - position.row)
 }
 
-fn crosswise_adjacent_position(position: &Position, other: &Position) -> bool {
-        let up_left = position.row > 0 && position.column > 0 && position.row - 1 == other.row && position.column - 1 == other.column;
-        let up_right = position.row > 0 && position.row - 1 == other.row && position.column + 1 == other.column;
-        let down_left = position.column > 0 && position.row + 1 == other.row && position.column - 1 == other.column;
-        let down_right = position.row + 1 == other.row && position.column + 1 == other.column;
-
-        up_left || up_right || down_left || down_right
-    }
-
 ascent_par!{
     relation plot(Position, char);
     lattice region(Position, char, Set<Position>);
@@ -53,8 +41,8 @@ ascent_par!{
     relation shared_external_pair(Position, Position, Position, Set<Position>);
     relation counted_shared_external_pair(Set<Position>, Position, usize);
 
-    adjacent(one, other) <-- plot(one, _), plot(other, _) if adjacent_position(one, other);
-    cross_adjacent(one, other) <-- plot(one, _), plot(other, _) if crosswise_adjacent_position(one, other);
+    adjacent(one, other) <-- plot(one, _), plot(other, _) if Neighbourhood::VonNeumann.contains(offset(one, other));
+    cross_adjacent(one, other) <-- plot(one, _), plot(other, _) if Neighbourhood::Diagonal.contains(offset(one, other));
 
     region(p, c, Set::singleton(*p)) <-- plot(p, c);
     region(p, c, other_set) <-- plot(p, c), adjacent(p, neighbour), plot(neighbour, c), region(neighbour, c, other_set);
@@ -156,9 +144,9 @@ pub fn part_two(input: &str) -> Option<u32> {
             unique_region(_, set), adjacent(one, other) if set.contains(one) && set.contains(other);
 
         // this really is a dumb way of approaching this problem, it'd be much better to write a simple rust function to generate adjacents
-        adjacent(one, other) <-- plot(one, _), plot(other, _), if adjacent_position(one, other);
-        adjacent(one, other) <-- edge(one, _), edge(other, _), if adjacent_position(one, other);
-        adjacent(one, other) <-- edge(_, one), edge(_, other), if adjacent_position(one, other);
+        adjacent(one, other) <-- plot(one, _), plot(other, _), if Neighbourhood::VonNeumann.contains(offset(one, other));
+        adjacent(one, other) <-- edge(one, _), edge(other, _), if Neighbourhood::VonNeumann.contains(offset(one, other));
+        adjacent(one, other) <-- edge(_, one), edge(_, other), if Neighbourhood::VonNeumann.contains(offset(one, other));
 
         area(set, set.len()) <--
             unique_region(_, set);
@@ -224,13 +212,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(1930));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(1206));
     }
 }
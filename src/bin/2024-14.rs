@@ -3,11 +3,13 @@ use itertools::Itertools;
 use regex::Regex;
 
 advent_of_code::solution!(14);
+use advent_of_code::template::Context;
+use advent_of_code::utils::vector::{Point, Vector};
 
 #[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
 struct Robot {
-    position: (u32, u32),
-    velocity: (i32, i32),
+    position: Point,
+    velocity: Vector,
 }
 
 
@@ -17,8 +19,8 @@ impl TryFrom<&str> for Robot {
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         let pattern = Regex::new(r"p=(\d+),(\d+) v=(-?\d+),(-?\d+)").unwrap();
         let captures = pattern.captures(value).ok_or(())?;
-        let position = (captures[1].parse::<u32>().map_err(|_| ())?, captures[2].parse::<u32>().map_err(|_| ())?);
-        let velocity = (captures[3].parse::<i32>().map_err(|_| ())?, captures[4].parse::<i32>().map_err(|_| ())?);
+        let position = Point::new(captures[1].parse().map_err(|_| ())?, captures[2].parse().map_err(|_| ())?);
+        let velocity = Vector::new(captures[3].parse().map_err(|_| ())?, captures[4].parse().map_err(|_| ())?);
         Ok(Robot { position, velocity })
 
     }
@@ -26,8 +28,8 @@ impl TryFrom<&str> for Robot {
 
 
 impl Robot {
-    fn step(&self, bounds: (u32, u32)) -> Self {
-        let new_position = ((self.position.0 as i32 + self.velocity.0).rem_euclid(bounds.0 as i32) as u32, (self.position.1 as i32 + self.velocity.1).rem_euclid(bounds.1 as i32) as u32);
+    fn step(&self, bounds: Vector) -> Self {
+        let new_position = (self.position + self.velocity).rem_euclid(bounds.x, bounds.y);
         Robot { position: new_position, velocity: self.velocity }
     }
 }
@@ -39,7 +41,7 @@ struct Map {
 
 
 impl Map {
-    fn memoized_step(&self, bounds: (u32, u32), memory: &mut HashMap<Map, Map>) -> Map {
+    fn memoized_step(&self, bounds: Vector, memory: &mut HashMap<Map, Map>) -> Map {
         if let Some(result) = memory.get(self) {
             println!("cache hit");
             result.clone()
@@ -50,11 +52,11 @@ impl Map {
         }
     }
 
-    fn print_map(&self, bounds: (u32, u32)) {
-        let mut visual = vec![vec!['.'; bounds.0 as usize]; bounds.1 as usize];
+    fn print_map(&self, bounds: Vector) {
+        let mut visual = vec![vec!['.'; bounds.x as usize]; bounds.y as usize];
 
         for robot in &self.robots {
-            visual[robot.position.1 as usize][robot.position.0 as usize] = '#';
+            visual[robot.position.y as usize][robot.position.x as usize] = '#';
         }
 
         for row in visual {
@@ -63,22 +65,22 @@ impl Map {
     }
 
     fn no_overlap(&self) -> bool {
-        self.robots.iter().map(|robot| robot.position).collect::<Vec<(u32, u32)>>().into_iter().unique().count() == self.robots.len()
+        self.robots.iter().map(|robot| robot.position).collect::<Vec<Point>>().into_iter().unique().count() == self.robots.len()
     }
 
 
-    fn robots_divided_by_quadrant(&self, bounds: (u32, u32)) -> Vec<Vec<Robot>> {
+    fn robots_divided_by_quadrant(&self, bounds: Vector) -> Vec<Vec<Robot>> {
 
         self.robots.iter().fold(
             vec![vec![]; 4],
             |mut acc, robot| {
-                let quadrant = if robot.position.0 < bounds.0 / 2 && robot.position.1 < bounds.1 / 2 {
+                let quadrant = if robot.position.x < bounds.x / 2 && robot.position.y < bounds.y / 2 {
                     Some(0)
-                } else if robot.position.0 > bounds.0 / 2 && robot.position.1 < bounds.1 / 2 {
+                } else if robot.position.x > bounds.x / 2 && robot.position.y < bounds.y / 2 {
                     Some(1)
-                } else if robot.position.0 < bounds.0 / 2 && robot.position.1 > bounds.1 / 2 {
+                } else if robot.position.x < bounds.x / 2 && robot.position.y > bounds.y / 2 {
                     Some(2)
-                } else if robot.position.0 > bounds.0 / 2 && robot.position.1 > bounds.1 / 2 {
+                } else if robot.position.x > bounds.x / 2 && robot.position.y > bounds.y / 2 {
                     Some(3)
                 } else {
                     None
@@ -101,16 +103,11 @@ impl TryFrom<&str> for Map {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &str, context: &Context) -> Option<u32> {
     let map = Map::try_from(input).ok()?;
 
-    // set bounds dependent on example imput vs real input
-    let bounds;
-    if map.robots.len() == 12 {
-        bounds = (11, 7);
-    } else {
-        bounds = (101, 103);
-    }
+    // the example uses a smaller area, see `data/2024/examples/14.params`
+    let bounds = Vector::new(context.param_or("width", 101), context.param_or("height", 103));
 
     let stepped = (0..100).fold(map, |map, _| map.memoized_step(bounds, &mut HashMap::new()));
 
@@ -125,16 +122,11 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(result)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str, context: &Context) -> Option<u32> {
     let mut map = Map::try_from(input).ok()?;
 
-    // set bounds dependent on example imput vs real input
-    let bounds;
-    if map.robots.len() == 12 {
-        bounds = (11, 7);
-    } else {
-        bounds = (101, 103);
-    }
+    // the example uses a smaller area, see `data/2024/examples/14.params`
+    let bounds = Vector::new(context.param_or("width", 101), context.param_or("height", 103));
 
     let mut memory = HashMap::new();
 
@@ -154,13 +146,15 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let (input, context) = advent_of_code::template::read_file_with_context("examples", PUZZLE);
+        let result = part_one(&input, &context);
         assert_eq!(result, Some(12));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let (input, context) = advent_of_code::template::read_file_with_context("examples", PUZZLE);
+        let result = part_two(&input, &context);
         assert_eq!(result, Some(1));
     }
 }
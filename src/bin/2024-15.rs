@@ -4,7 +4,8 @@ advent_of_code::solution!(15);
 
 
 use advent_of_code::define_nodes;
-use advent_of_code::utils::map::{Map, BoxedMap, Direction, Position};
+use advent_of_code::utils::grid::Grid;
+use advent_of_code::utils::map::{parse_grid_with_markers, Map, Direction, Position};
 
 define_nodes!(
     '#' => Obstacle,
@@ -17,7 +18,7 @@ define_nodes!(
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct RobotMap {
-    pub base: BoxedMap<Node>,
+    pub base: Grid<Node>,
     robot_location: Position,
 }
 fn gps_coordinate(position: &Position) -> u32 {
@@ -138,38 +139,19 @@ impl TryFrom<&str> for RobotMap {
     type Error = ();
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let data = value
-            .lines()
-            .map(|line| line.chars().map(|c| Node::try_from(c)).collect::<Result<Vec<Node>, ()>>())
-            .collect::<Result<Vec<Vec<Node>>, ()>>()?;
+        let (base, markers) = parse_grid_with_markers(value, &[Node::Robot]).map_err(|_| ())?;
 
-
-        let base = BoxedMap::try_from(data)?;
-
-        let robot_location = base.position_iter().find(|p| {base.get(p).unwrap() == &Node::Robot}).ok_or(())?;
+        let robot_location = markers.first(&Node::Robot).ok_or(())?;
 
         Ok(Self { base, robot_location })
     }
 }
 
-fn try_parse_move(value: &char) -> Result<Direction, ()> {
-    match value {
-        '^' => Ok(Direction::Up),
-        'v' => Ok(Direction::Down),
-        '<' => Ok(Direction::Left),
-        '>' => Ok(Direction::Right),
-        _ => {
-            println!("Invalid move: {}", value);
-            Err(())
-        }
-    }
-}
-
 pub fn part_one(input: &str) -> Option<u32> {
     let split_input = input.split("\n\n").collect::<Vec<&str>>();
 
     let mut grid = RobotMap::try_from(*split_input.get(0)?).ok()?;
-    let moves = split_input.get(1)?.trim().chars().filter(|c| *c != '\n').map(|c| try_parse_move(&c)).collect::<Result<Vec<Direction>, ()>>().ok()?;
+    let moves = split_input.get(1)?.trim().chars().filter(|c| *c != '\n').map(Direction::try_from).collect::<Result<Vec<Direction>, ()>>().ok()?;
 
     moves.iter().for_each(|direction| grid.move_robot(direction, true));
 
@@ -195,7 +177,7 @@ pub fn part_two(input: &str) -> Option<u32> {
         }).collect::<String>();
 
     let mut grid = RobotMap::try_from(new_map.as_str()).ok()?;
-    let moves = split_input.get(1)?.trim().chars().filter(|c| *c != '\n').map(|c| try_parse_move(&c)).collect::<Result<Vec<Direction>, ()>>().ok()?;
+    let moves = split_input.get(1)?.trim().chars().filter(|c| *c != '\n').map(Direction::try_from).collect::<Result<Vec<Direction>, ()>>().ok()?;
 
     moves.iter().for_each(|direction| grid.move_robot(direction, false));
 
@@ -208,13 +190,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(10092));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(9021));
     }
 }
@@ -0,0 +1,66 @@
+
+advent_of_code::solution!(16);
+
+use std::collections::HashSet;
+
+use advent_of_code::define_nodes;
+use advent_of_code::utils::map::{parse_grid_with_markers, Direction};
+use advent_of_code::utils::search::{astar, Pose};
+use advent_of_code::utils::vector::Vector;
+
+
+define_nodes!(
+    '#' => Obstacle,
+    '.' => Empty,
+    'S' => Start,
+    'E' => Finish,
+);
+
+// returns the lowest score and the number of tiles on any of the best paths
+fn run_search(input: &str) -> Option<(u64, usize)> {
+    let (map, markers) = parse_grid_with_markers(input, &[Node::Start, Node::Finish]).ok()?;
+
+    let start = markers.first(&Node::Start)?;
+    let finish = markers.first(&Node::Finish)?;
+
+    let result = astar(
+        [Pose::new(start, Direction::Right)],
+        |pose| pose.successors(&map, 1, 1000, |node| *node != Node::Obstacle),
+        |pose| Vector::from(pose.position).manhattan(finish.into()),
+        |pose| pose.position == finish,
+    );
+
+    let (score, ends) = result.nearest(Pose::facings(finish))?;
+    let tiles = result
+        .states_on_paths_to(&ends)
+        .into_iter()
+        .map(|pose| pose.position)
+        .collect::<HashSet<_>>();
+
+    Some((score, tiles.len()))
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    run_search(input).map(|(score, _)| score as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    run_search(input).map(|(_, tiles)| tiles as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(7036));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(45));
+    }
+}
@@ -1,3 +1,4 @@
 pub mod template;
+pub mod utils;
 
 // Use this file to add helper functions and additional modules.
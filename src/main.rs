@@ -1,79 +1,258 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, answer, download, read, scaffold, solve, test_day, time, watch,
+};
+use advent_of_code::template::{alloc, registry::Solution};
 use args::{parse, AppArguments};
+use std::process;
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::Puzzle;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        commands::{scaffold, time::CompareOptions},
+        runner::{BenchConfig, Timeouts},
+        Day, Puzzle, Year,
+    };
+    use std::{process, time::Duration};
 
     pub enum AppArguments {
+        Answer {
+            puzzle: Puzzle,
+            part: u8,
+            answer: String,
+        },
         Download {
-            day: Day,
+            puzzle: Puzzle,
         },
         Read {
-            day: Day,
+            puzzle: Puzzle,
         },
         Scaffold {
-            day: Day,
+            puzzle: Puzzle,
+            template: String,
             download: bool,
             overwrite: bool,
         },
         Solve {
-            day: Day,
+            puzzle: Puzzle,
             release: bool,
             dhat: bool,
+            allocations: bool,
+            profile: bool,
             submit: Option<u8>,
         },
         All {
+            year: Year,
+            release: bool,
+            in_process: bool,
+            allocations: bool,
+            timeouts: Timeouts,
+            jobs: usize,
+        },
+        TestDay {
+            puzzle: Puzzle,
+            overwrite: bool,
+        },
+        Watch {
+            puzzle: Puzzle,
             release: bool,
         },
         Time {
+            year: Year,
             all: bool,
             day: Option<Day>,
             store: bool,
+            bench_config: BenchConfig,
+            in_process: bool,
+            allocations: bool,
+            timeouts: Timeouts,
+            compare: Option<CompareOptions>,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
+    /// Parses the bench flags of `time`, falling back to the `AOC_BENCH_*` environment variables.
+    fn parse_bench_config(
+        args: &mut pico_args::Arguments,
+    ) -> Result<BenchConfig, Box<dyn std::error::Error>> {
+        let mut config = BenchConfig::from_env();
+
+        if let Some(ms) = args.opt_value_from_str("--warmup")? {
+            config.warmup = Duration::from_millis(ms);
+        }
+        if let Some(ms) = args.opt_value_from_str("--bench-time")? {
+            config.bench_time = Duration::from_millis(ms);
+        }
+        if let Some(n) = args.opt_value_from_str("--min-samples")? {
+            config.min_samples = n;
+        }
+        if let Some(n) = args.opt_value_from_str("--max-samples")? {
+            config.max_samples = n;
+        }
+
+        if config.min_samples > config.max_samples {
+            return Err("`--min-samples` must not be greater than `--max-samples`.".into());
+        }
+
+        Ok(config)
+    }
+
+    /// Parses `--day-timeout <secs>` and `--part-timeout <secs>` of `all` and `time`.
+    /// Days that run in-process can't be killed, so the day timeout is rejected for them.
+    fn parse_timeouts(
+        args: &mut pico_args::Arguments,
+        in_process: bool,
+    ) -> Result<Timeouts, Box<dyn std::error::Error>> {
+        let mut parse_secs = |key: &'static str| -> Result<_, Box<dyn std::error::Error>> {
+            match args.opt_value_from_str::<_, f64>(key)? {
+                None => Ok(None),
+                Some(secs) => Duration::try_from_secs_f64(secs)
+                    .map(Some)
+                    .map_err(|_| format!("`{key}` must be a positive number of seconds.").into()),
+            }
+        };
+
+        let timeouts = Timeouts {
+            day: parse_secs("--day-timeout")?,
+            part: parse_secs("--part-timeout")?,
+        };
+
+        if in_process && timeouts.day.is_some() {
+            return Err("`--day-timeout` can't be combined with `--in-process`.".into());
+        }
+
+        Ok(timeouts)
+    }
+
+    /// Parses `--compare`, `--compare-rev <rev>` and `--threshold <percent>` of `time`.
+    fn parse_compare_options(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<CompareOptions>, Box<dyn std::error::Error>> {
+        let latest = args.contains("--compare");
+        let rev: Option<String> = args.opt_value_from_str("--compare-rev")?;
+
+        if latest && rev.is_some() {
+            return Err("`--compare` can't be combined with `--compare-rev`.".into());
+        }
+        if !latest && rev.is_none() {
+            return Ok(None);
+        }
+
+        let threshold_percent = args.opt_value_from_str("--threshold")?.unwrap_or(10.0);
+
+        Ok(Some(CompareOptions {
+            rev,
+            threshold_percent,
+        }))
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-            },
+        let subcommand = args.subcommand()?;
+
+        // NOTE: parsed before free arguments, which would otherwise pick up the flag.
+        let year_arg: Option<Year> = args.opt_value_from_str("--year")?;
+
+        // `--year` takes precedence over the `AOC_YEAR` environment variable.
+        let year = || {
+            year_arg
+                .or_else(Year::from_env)
+                .ok_or("pass `--year` or set `AOC_YEAR` to a valid year.")
+        };
+
+        let app_args = match subcommand.as_deref() {
+            Some("all") => {
+                let in_process = args.contains("--in-process");
+                let jobs = args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1);
+
+                if jobs == 0 {
+                    return Err("`--jobs` must be at least 1.".into());
+                }
+                if in_process && jobs > 1 {
+                    return Err("`--jobs` can't be combined with `--in-process`.".into());
+                }
+
+                AppArguments::All {
+                    year: year()?,
+                    release: args.contains("--release"),
+                    in_process,
+                    allocations: args.contains("--allocations"),
+                    timeouts: parse_timeouts(&mut args, in_process)?,
+                    jobs,
+                }
+            }
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let in_process = args.contains("--in-process");
+                let allocations = args.contains("--allocations");
+                let bench_config = parse_bench_config(&mut args)?;
+                let timeouts = parse_timeouts(&mut args, in_process)?;
+                let compare = parse_compare_options(&mut args)?;
 
                 AppArguments::Time {
+                    year: year()?,
                     all,
                     day: args.opt_free_from_str()?,
                     store,
+                    bench_config,
+                    in_process,
+                    allocations,
+                    timeouts,
+                    compare,
                 }
             }
+            Some("answer") => AppArguments::Answer {
+                puzzle: Puzzle::new(year()?, args.free_from_str()?),
+                part: args.free_from_str()?,
+                answer: args.free_from_str()?,
+            },
+            Some("test-day") => AppArguments::TestDay {
+                puzzle: Puzzle::new(year()?, args.free_from_str()?),
+                overwrite: args.contains("--overwrite"),
+            },
+            Some("watch") => AppArguments::Watch {
+                puzzle: Puzzle::new(year()?, args.free_from_str()?),
+                release: args.contains("--release"),
+            },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: Puzzle::new(year()?, args.free_from_str()?),
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: Puzzle::new(year()?, args.free_from_str()?),
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                puzzle: Puzzle::new(year()?, args.free_from_str()?),
+                template: args
+                    .opt_value_from_str("--template")?
+                    .unwrap_or_else(|| scaffold::DEFAULT_TEMPLATE.into()),
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
-            },
+            Some("solve") => {
+                let dhat = args.contains("--dhat");
+                let allocations = args.contains("--allocations");
+                let profile = args.contains("--profile");
+
+                if dhat && allocations {
+                    return Err("`--dhat` can't be combined with `--allocations`.".into());
+                }
+                if dhat && profile {
+                    return Err("`--dhat` can't be combined with `--profile`.".into());
+                }
+
+                AppArguments::Solve {
+                    puzzle: Puzzle::new(year()?, args.free_from_str()?),
+                    release: args.contains("--release"),
+                    submit: args.opt_value_from_str("--submit")?,
+                    dhat,
+                    allocations,
+                    profile,
+                }
+            }
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
             Some(x) => {
@@ -95,6 +274,49 @@ mod args {
     }
 }
 
+#[cfg(all(feature = "registry", feature = "dhat-heap"))]
+compile_error!("the `registry` and `dhat-heap` features can not be combined.");
+
+#[cfg(all(feature = "count-allocations", feature = "dhat-heap"))]
+compile_error!("the `count-allocations` and `dhat-heap` features can not be combined.");
+
+/// Solutions linked into this binary share its allocator.
+#[cfg(all(feature = "registry", feature = "count-allocations"))]
+#[global_allocator]
+static ALLOC: advent_of_code::template::alloc::CountingAlloc =
+    advent_of_code::template::alloc::CountingAlloc;
+
+/// Solutions linked into this binary by the build script.
+#[cfg(feature = "registry")]
+mod registry {
+    include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+}
+
+/// Returns the in-process solution registry if requested.
+/// If this binary was built without the `registry` feature, the current command is relaunched with it.
+fn registered_solutions(in_process: bool) -> Option<&'static [Solution]> {
+    if !in_process {
+        return None;
+    }
+
+    #[cfg(feature = "registry")]
+    {
+        Some(registry::SOLUTIONS)
+    }
+
+    #[cfg(not(feature = "registry"))]
+    {
+        advent_of_code::template::registry::relaunch_with_registry()
+    }
+}
+
+/// Relaunches the current command with the `count-allocations` feature if requested and not enabled yet.
+fn ensure_allocations(allocations: bool) {
+    if allocations && !alloc::ENABLED {
+        advent_of_code::template::registry::relaunch_with_features(&[alloc::FEATURE]);
+    }
+}
+
 fn main() {
     match parse() {
         Err(err) => {
@@ -102,38 +324,92 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold {
+            AppArguments::All {
+                year,
+                release,
+                in_process,
+                allocations,
+                timeouts,
+                jobs,
+            } => {
+                ensure_allocations(allocations);
+                all::handle(
+                    year,
+                    release,
+                    &timeouts,
+                    jobs,
+                    registered_solutions(in_process),
+                );
+            }
+            AppArguments::Time {
+                year,
                 day,
+                all,
+                store,
+                bench_config,
+                in_process,
+                allocations,
+                timeouts,
+                compare,
+            } => {
+                ensure_allocations(allocations);
+                time::handle(
+                    year,
+                    day,
+                    all,
+                    store,
+                    &bench_config,
+                    &timeouts,
+                    registered_solutions(in_process),
+                    compare.as_ref(),
+                );
+            }
+            AppArguments::Answer {
+                puzzle,
+                part,
+                answer,
+            } => answer::handle(puzzle, part, &answer),
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::TestDay { puzzle, overwrite } => test_day::handle(puzzle, overwrite),
+            AppArguments::Watch { puzzle, release } => watch::handle(puzzle, release),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Scaffold {
+                puzzle,
+                template,
                 download,
                 overwrite,
             } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
+                // NOTE: download first, so the template can use the title of the puzzle.
+                // A failed download still scaffolds the day, the title then falls back to `Day N`.
+                let downloaded = !download || download::try_handle(puzzle);
+                scaffold::handle(puzzle, &template, overwrite);
+                if !downloaded {
+                    process::exit(1);
                 }
             }
             AppArguments::Solve {
-                day,
+                puzzle,
                 release,
                 dhat,
+                allocations,
+                profile,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+            } => solve::handle(puzzle, release, dhat, allocations, profile, submit),
             #[cfg(feature = "today")]
             AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                match Puzzle::today() {
+                    Some(puzzle) => {
+                        let downloaded = download::try_handle(puzzle);
+                        scaffold::handle(puzzle, scaffold::DEFAULT_TEMPLATE, false);
+                        if !downloaded {
+                            process::exit(1);
+                        }
+                        read::handle(puzzle)
                     }
                     None => {
                         eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            "`today` command can only be run between the 1st of december and \
+                            the last day of the event. Please use `scaffold` with a specific day."
                         );
                         process::exit(1)
                     }
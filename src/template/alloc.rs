@@ -0,0 +1,185 @@
+/// A global allocator that counts heap allocations, enabled with the `count-allocations` feature.
+/// The `solution!` macro installs it, and the runner measures the allocations of the first execution of every part.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Name of the cargo feature that installs the [`CountingAlloc`].
+pub const FEATURE: &str = "count-allocations";
+
+/// Whether this binary was built with the `count-allocations` feature.
+pub const ENABLED: bool = cfg!(feature = "count-allocations");
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+static CURRENT_BYTES: AtomicU64 = AtomicU64::new(0);
+static PEAK_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// Wraps the system allocator and counts allocations, allocated bytes and the peak of bytes in use.
+pub struct CountingAlloc;
+
+fn record_allocation(size: usize) {
+    let size = size as u64;
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+    let current = CURRENT_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+}
+
+fn record_deallocation(size: usize) {
+    CURRENT_BYTES.fetch_sub(size as u64, Ordering::Relaxed);
+}
+
+// SAFETY: all allocations are delegated to the system allocator, only the counters are updated here.
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_deallocation(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // NOTE: a reallocation counts as a new allocation of the new size that replaces the old one.
+            record_deallocation(layout.size());
+            record_allocation(new_size);
+        }
+        new_ptr
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Heap usage of a single execution of a solution part.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, including reallocations.
+    pub allocations: u64,
+    /// Total number of bytes allocated.
+    pub bytes: u64,
+    /// Maximum number of bytes in use at the same time.
+    pub peak: u64,
+}
+
+/// Measures the heap usage between its creation and [`AllocMeasurement::finish`].
+/// Counters are global, so allocations of other threads are included.
+pub struct AllocMeasurement {
+    allocations: u64,
+    bytes: u64,
+    current: u64,
+}
+
+impl AllocMeasurement {
+    #[must_use]
+    pub fn start() -> Self {
+        let current = CURRENT_BYTES.load(Ordering::Relaxed);
+        PEAK_BYTES.store(current, Ordering::Relaxed);
+
+        Self {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+            current,
+        }
+    }
+
+    /// Returns the heap usage since the start of the measurement, `None` if the counting allocator is not installed.
+    #[must_use]
+    pub fn finish(self) -> Option<AllocStats> {
+        ENABLED.then(|| self.stats())
+    }
+
+    fn stats(&self) -> AllocStats {
+        AllocStats {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed) - self.allocations,
+            bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - self.bytes,
+            peak: PEAK_BYTES
+                .load(Ordering::Relaxed)
+                .saturating_sub(self.current),
+        }
+    }
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.5 KiB`.
+#[must_use]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+impl AllocStats {
+    /// Formats the stats for display, e.g. `12 allocs, 3.4 KiB (peak 1.2 KiB)`.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        format!(
+            "{} allocs, {} (peak {})",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_bytes, AllocMeasurement, AllocStats, CountingAlloc};
+    use std::alloc::{GlobalAlloc, Layout};
+
+    #[test]
+    fn counts_allocations() {
+        let measurement = AllocMeasurement::start();
+
+        // SAFETY: pointers are only deallocated with the layout they were allocated with.
+        unsafe {
+            let a = CountingAlloc.alloc(Layout::from_size_align(64, 8).unwrap());
+            let b = CountingAlloc.alloc(Layout::from_size_align(32, 8).unwrap());
+            CountingAlloc.dealloc(a, Layout::from_size_align(64, 8).unwrap());
+            let b = CountingAlloc.realloc(b, Layout::from_size_align(32, 8).unwrap(), 128);
+            CountingAlloc.dealloc(b, Layout::from_size_align(128, 8).unwrap());
+        }
+
+        assert_eq!(
+            measurement.stats(),
+            AllocStats {
+                allocations: 3,
+                bytes: 224,
+                peak: 128,
+            }
+        );
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
@@ -0,0 +1,152 @@
+/// Known answers for a day, used to detect regressions when re-running solutions.
+use std::{collections::HashMap, fs, io::Error, path::PathBuf, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::Puzzle;
+
+static ANSWERS_FOLDER: &str = "answers";
+
+/// Represents the known answers of a single day.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Answers {
+    fn get_path(puzzle: Puzzle) -> PathBuf {
+        puzzle.data_path(ANSWERS_FOLDER, "json")
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns empty answers.
+    pub fn read_from_file(puzzle: Puzzle) -> Self {
+        fs::read_to_string(Self::get_path(puzzle))
+            .map_err(|x| x.to_string())
+            .and_then(Answers::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self, puzzle: Puzzle) -> Result<(), Error> {
+        fs::create_dir_all(puzzle.data_dir(ANSWERS_FOLDER))?;
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(Self::get_path(puzzle))?;
+        json.format_to(&mut file)
+    }
+
+    /// Returns the known answer of a part.
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Sets the known answer of a part. Parts other than 1 and 2 are ignored.
+    pub fn set(&mut self, part: u8, answer: &str) {
+        match part {
+            1 => self.part_1 = Some(answer.into()),
+            2 => self.part_2 = Some(answer.into()),
+            _ => {}
+        }
+    }
+
+    /// Checks an answer against the known answer of a part.
+    /// Returns `None` if no answer is known yet.
+    pub fn check(&self, part: u8, answer: &str) -> Option<bool> {
+        self.get(part).map(|known| known == answer)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "part_1".into(),
+            value.part_1.map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "part_2".into(),
+            value.part_2.map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let part = |key: &str| match json.get(key) {
+            None => Ok(None),
+            Some(v) if v.is_null() => Ok(None),
+            Some(v) => v
+                .get::<String>()
+                .cloned()
+                .map(Some)
+                .ok_or(format!("expected `json.{key}` to be null or string.")),
+        };
+
+        Ok(Answers {
+            part_1: part("part_1")?,
+            part_2: part("part_2")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Answers;
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn handles_json_answers() {
+        let json = r#"{ "part_1": "42", "part_2": null }"#.to_string();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.part_1, Some("42".into()));
+        assert_eq!(answers.part_2, None);
+    }
+
+    #[test]
+    fn handles_missing_parts() {
+        let answers = Answers::try_from("{}".to_string()).unwrap();
+        assert_eq!(answers, Answers::default());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        Answers::try_from(r#"{ "part_1": 42 }"#.to_string()).unwrap();
+    }
+
+    #[test]
+    fn roundtrips_answers() {
+        let mut answers = Answers::default();
+        answers.set(2, "foo\nbar");
+        let json = JsonValue::from(answers.clone()).stringify().unwrap();
+        assert_eq!(Answers::try_from(json).unwrap(), answers);
+    }
+
+    #[test]
+    fn checks_answers() {
+        let mut answers = Answers::default();
+        answers.set(1, "42");
+        assert_eq!(answers.check(1, "42"), Some(true));
+        assert_eq!(answers.check(1, "41"), Some(false));
+        assert_eq!(answers.check(2, "42"), None);
+    }
+}
@@ -0,0 +1,633 @@
+/// Built-in client for the Advent of Code website.
+/// Downloads inputs and puzzle descriptions and submits answers, authenticated with the session cookie of the user.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::template::Puzzle;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable that overrides the base URL, e.g. to point the client at a local stand-in server.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+/// Environment variable that holds the session cookie. Takes precedence over the session file.
+pub const SESSION_ENV: &str = "ADVENT_OF_CODE_SESSION";
+
+/// Name of the file in the home directory that holds the session cookie.
+pub const SESSION_FILE: &str = ".adventofcode.session";
+
+/// Environment variable that overrides the `User-Agent` header, e.g. to add contact details.
+pub const USER_AGENT_ENV: &str = "AOC_USER_AGENT";
+
+/// Names this crate and, if `repository` is set in `Cargo.toml`, where to find it.
+fn default_user_agent() -> String {
+    let name = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+    match env!("CARGO_PKG_REPOSITORY") {
+        "" => name.into(),
+        repository => format!("{name} ({repository})"),
+    }
+}
+
+#[derive(Debug)]
+pub enum AocClientError {
+    /// Neither the environment variable nor the session file contain a session cookie.
+    SessionNotFound,
+    /// The website rejected the session cookie.
+    Unauthorized,
+    /// The puzzle does not exist or is not unlocked yet.
+    NotFound,
+    /// The website responded with an unexpected status code.
+    BadStatus(u16),
+    /// The website could not be reached.
+    Transport(String),
+    /// The response to a submit could not be understood. Contains the response message.
+    UnexpectedResponse(String),
+    Io(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set `{SESSION_ENV}` or create `~/{SESSION_FILE}`."
+            ),
+            AocClientError::Unauthorized => {
+                write!(f, "the session cookie was rejected, it might have expired.")
+            }
+            AocClientError::NotFound => {
+                write!(f, "the puzzle was not found, it might not be unlocked yet.")
+            }
+            AocClientError::BadStatus(status) => {
+                write!(f, "the website responded with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "the website could not be reached: {e}"),
+            AocClientError::UnexpectedResponse(response) => {
+                write!(f, "unexpected response to submit:\n{response}")
+            }
+            AocClientError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::Io(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(400 | 401, _) => AocClientError::Unauthorized,
+            ureq::Error::Status(404, _) => AocClientError::NotFound,
+            ureq::Error::Status(status, _) => AocClientError::BadStatus(status),
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+/// Hint given for an incorrect answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// The verdict of the website on a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubmissionVerdict {
+    Correct,
+    /// `wait` is the cooldown before the next submit is accepted.
+    Incorrect {
+        hint: Option<Hint>,
+        wait: Option<Duration>,
+    },
+    /// The answer was not checked because the cooldown of an earlier submit is still in effect.
+    RateLimited {
+        wait: Duration,
+    },
+    AlreadySolved,
+    /// The part is not unlocked yet.
+    WrongLevel,
+}
+
+impl SubmissionVerdict {
+    /// Reads the verdict from the response message to a submit.
+    /// The website gives the same response for solved and locked parts, these are reported as [`SubmissionVerdict::WrongLevel`].
+    fn parse(response: &str) -> Option<Self> {
+        if response.contains("That's the right answer") {
+            Some(SubmissionVerdict::Correct)
+        } else if response.contains("You gave an answer too recently") {
+            // NOTE: fall back to the shortest cooldown if the message changes.
+            let wait = parse_wait(response).unwrap_or(Duration::from_secs(60));
+            Some(SubmissionVerdict::RateLimited { wait })
+        } else if response.contains("That's not the right answer") {
+            let hint = if response.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if response.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            Some(SubmissionVerdict::Incorrect {
+                hint,
+                wait: parse_wait(response),
+            })
+        } else if response.contains("You don't seem to be solving the right level") {
+            Some(SubmissionVerdict::WrongLevel)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses cooldowns like "You have 1m 20s left to wait" or "please wait 5 minutes before trying again".
+fn parse_wait(response: &str) -> Option<Duration> {
+    if let Some(index) = response.find(" left to wait") {
+        let words = response[..index].rsplit("You have ").next()?;
+        let seconds = words
+            .split_whitespace()
+            .map(|word| {
+                let (value, unit) = word.split_at(word.find(|c: char| !c.is_ascii_digit())?);
+                let value: u64 = value.parse().ok()?;
+                match unit {
+                    "h" => Some(value * 3600),
+                    "m" => Some(value * 60),
+                    "s" => Some(value),
+                    _ => None,
+                }
+            })
+            .sum::<Option<u64>>()?;
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let rest = &response[response.find("wait ")? + 5..];
+    let mut words = rest.split_whitespace();
+    let value = match words.next()? {
+        "one" | "a" => 1,
+        x => x.parse().ok()?,
+    };
+    match words.next()? {
+        unit if unit.starts_with("minute") => Some(Duration::from_secs(value * 60)),
+        unit if unit.starts_with("second") => Some(Duration::from_secs(value)),
+        _ => None,
+    }
+}
+
+/// A client for the Advent of Code website.
+#[derive(Clone, Debug)]
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    user_agent: String,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            user_agent: default_user_agent(),
+        }
+    }
+
+    /// Sets the `User-Agent` header sent with every request.
+    pub fn with_user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = user_agent.to_string();
+        self
+    }
+
+    /// Configures a client from the session cookie and optionally `AOC_BASE_URL` and `AOC_USER_AGENT`.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let client = Self::new(&base_url, &read_session()?);
+
+        Ok(match env::var(USER_AGENT_ENV) {
+            Ok(user_agent) if !user_agent.trim().is_empty() => client.with_user_agent(&user_agent),
+            _ => client,
+        })
+    }
+
+    fn day_url(&self, puzzle: Puzzle) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        let response = ureq::get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", &self.user_agent)
+            .call()?;
+        Ok(response.into_string()?)
+    }
+
+    /// Fetches the puzzle input of a puzzle.
+    pub fn get_input(&self, puzzle: Puzzle) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_url(puzzle)))
+    }
+
+    /// Fetches the puzzle description of a puzzle as markdown.
+    /// Contains part two once part one has been solved.
+    pub fn get_puzzle(&self, puzzle: Puzzle) -> Result<String, AocClientError> {
+        Ok(html_to_markdown(&self.get(&self.day_url(puzzle))?))
+    }
+
+    /// Counts the parts of a puzzle that have been solved.
+    pub fn get_solved_parts(&self, puzzle: Puzzle) -> Result<u8, AocClientError> {
+        let page = self.get(&self.day_url(puzzle))?;
+        let solved = page.matches("Your puzzle answer was").count();
+        Ok(u8::try_from(solved).unwrap_or(u8::MAX))
+    }
+
+    /// Submits an answer and returns the verdict of the website.
+    pub fn submit(
+        &self,
+        puzzle: Puzzle,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmissionVerdict, AocClientError> {
+        let response = ureq::post(&format!("{}/answer", self.day_url(puzzle)))
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", &self.user_agent)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?;
+
+        let response = html_to_markdown(&response.into_string()?);
+
+        match SubmissionVerdict::parse(&response) {
+            Some(SubmissionVerdict::WrongLevel) if self.get_solved_parts(puzzle)? >= part => {
+                Ok(SubmissionVerdict::AlreadySolved)
+            }
+            Some(verdict) => Ok(verdict),
+            None => Err(AocClientError::UnexpectedResponse(response)),
+        }
+    }
+}
+
+/// Reads the session cookie from the environment, or the session file in the home directory.
+pub fn read_session() -> Result<String, AocClientError> {
+    let session = env::var(SESSION_ENV).ok().or_else(|| {
+        let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+        fs::read_to_string(PathBuf::from(home).join(SESSION_FILE)).ok()
+    });
+
+    session
+        .map(|x| x.trim().trim_start_matches("session=").to_string())
+        .filter(|x| !x.is_empty())
+        .ok_or(AocClientError::SessionNotFound)
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn get_input_path(puzzle: Puzzle) -> PathBuf {
+    puzzle.data_path("inputs", "txt")
+}
+
+fn get_puzzle_path(puzzle: Puzzle) -> PathBuf {
+    puzzle.data_path("puzzles", "md")
+}
+
+fn write_file(path: &Path, content: &str) -> Result<(), AocClientError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, content)?;
+    Ok(())
+}
+
+/// Downloads input and puzzle description of a puzzle to the `data` directory.
+pub fn download(puzzle: Puzzle) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+
+    let input_path = get_input_path(puzzle);
+    let puzzle_path = get_puzzle_path(puzzle);
+
+    write_file(&input_path, &client.get_input(puzzle)?)?;
+    write_file(&puzzle_path, &client.get_puzzle(puzzle)?)?;
+
+    println!("---");
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+    Ok(())
+}
+
+/// Downloads the description of a puzzle to the `data` directory and returns it.
+pub fn download_puzzle(puzzle: Puzzle) -> Result<String, AocClientError> {
+    let markdown = AocClient::from_env()?.get_puzzle(puzzle)?;
+    write_file(&get_puzzle_path(puzzle), &markdown)?;
+    Ok(markdown)
+}
+
+/// Fetches the description of a puzzle, stores it and prints it.
+pub fn read(puzzle: Puzzle) -> Result<(), AocClientError> {
+    println!("{}", download_puzzle(puzzle)?);
+    Ok(())
+}
+
+/// Submits an answer and returns the verdict of the website.
+pub fn submit(puzzle: Puzzle, part: u8, result: &str) -> Result<SubmissionVerdict, AocClientError> {
+    AocClient::from_env()?.submit(puzzle, part, result)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Converts the `<article>` elements of an AoC page to markdown.
+/// Only handles the small set of tags used by puzzle descriptions and answer responses.
+fn html_to_markdown(html: &str) -> String {
+    let articles: Vec<&str> = html
+        .split("<article")
+        .skip(1)
+        .filter_map(|x| x.split_once('>').map(|(_, body)| body))
+        .filter_map(|x| x.split("</article>").next())
+        .collect();
+
+    let mut out = String::new();
+    let mut links: Vec<String> = vec![];
+    let mut in_pre = false;
+
+    for article in articles {
+        let mut rest = article;
+
+        while !rest.is_empty() {
+            let Some(start) = rest.find('<') else {
+                out.push_str(&decode_entities(rest));
+                break;
+            };
+
+            out.push_str(&decode_entities(&rest[..start]));
+
+            let Some(end) = rest[start..].find('>') else {
+                break;
+            };
+
+            let tag = &rest[start + 1..start + end];
+            rest = &rest[start + end + 1..];
+
+            let (name, attributes) = tag.split_once(' ').unwrap_or((tag, ""));
+            let name = name.to_ascii_lowercase();
+
+            match name.as_str() {
+                "pre" => {
+                    in_pre = true;
+                    out.push_str("```\n");
+                }
+                "/pre" => {
+                    in_pre = false;
+                    if !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("```\n\n");
+                }
+                // NOTE: markup inside of code blocks would corrupt the example input.
+                _ if in_pre => {}
+                "h2" => out.push_str("## "),
+                "/h2" | "/p" | "/ul" => out.push_str("\n\n"),
+                "li" => out.push_str("- "),
+                "/li" | "br" | "br/" => out.push('\n'),
+                "code" | "/code" => out.push('`'),
+                "em" | "/em" => out.push('*'),
+                "a" => {
+                    links.push(attribute(attributes, "href").unwrap_or_default());
+                    out.push('[');
+                }
+                "/a" => {
+                    let href = links.pop().unwrap_or_default();
+                    out.push_str(&format!("]({href})"));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut markdown = out.trim().to_string();
+    while markdown.contains("\n\n\n") {
+        markdown = markdown.replace("\n\n\n", "\n\n");
+    }
+    markdown.push('\n');
+    markdown
+}
+
+fn attribute(attributes: &str, key: &str) -> Option<String> {
+    let start = attributes.find(&format!("{key}=\""))? + key.len() + 2;
+    let len = attributes[start..].find('"')?;
+    Some(decode_entities(&attributes[start..start + len]))
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{html_to_markdown, AocClient, AocClientError, Hint, SubmissionVerdict};
+    use crate::template::{Day, Puzzle};
+    use crate::year;
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+        time::Duration,
+    };
+
+    fn puzzle(day: u8) -> Puzzle {
+        Puzzle::new(year!(2024), Day::new(day).unwrap())
+    }
+
+    const PUZZLE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2><p>For example:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<p>A total distance of <code><em>11</em></code> &amp; <a href="/2024/about">more</a>!</p>
+</article>
+<p>Your puzzle answer was <code>42</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>The score is <em><code>31</code></em>.</p></article>
+</main></body></html>"#;
+
+    /// Serves canned responses, one per connection, and returns the raw requests it received.
+    fn serve(
+        responses: Vec<(&'static str, &'static str)>,
+    ) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|(status, body)| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                    let mut request = String::new();
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                            content_length = len.trim().parse().unwrap();
+                        }
+                        request.push_str(&line);
+                        if line == "\r\n" {
+                            break;
+                        }
+                    }
+
+                    let mut body_buf = vec![0; content_length];
+                    reader.read_exact(&mut body_buf).unwrap();
+                    request.push_str(&String::from_utf8_lossy(&body_buf));
+
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+
+                    request
+                })
+                .collect()
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn converts_puzzles_to_markdown() {
+        assert_eq!(
+            html_to_markdown(PUZZLE),
+            "## --- Day 1: Historian Hysteria ---\n\nFor example:\n\n```\n3   4\n4   3\n```\n\nA total distance of `*11*` & [more](/2024/about)!\n\n## --- Part Two ---\n\nThe score is *`31`*.\n"
+        );
+    }
+
+    #[test]
+    fn fetches_inputs() {
+        let (url, server) = serve(vec![("200 OK", "1 2\n3 4\n")]);
+        let client = AocClient::new(&url, "abc");
+
+        assert_eq!(client.get_input(puzzle(1)).unwrap(), "1 2\n3 4\n");
+
+        let request = &server.join().unwrap()[0];
+        assert!(request.starts_with("GET /2024/day/1/input "));
+        assert!(request.contains("session=abc"));
+        assert!(request.contains(concat!(
+            "User-Agent: advent_of_code/",
+            env!("CARGO_PKG_VERSION")
+        )));
+    }
+
+    #[test]
+    fn sends_custom_user_agents() {
+        let (url, server) = serve(vec![("200 OK", "")]);
+        let client = AocClient::new(&url, "abc").with_user_agent("me@example.com");
+
+        client.get_input(puzzle(1)).unwrap();
+
+        let request = &server.join().unwrap()[0];
+        assert!(request.contains("User-Agent: me@example.com\r\n"));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (url, server) = serve(vec![(
+            "200 OK",
+            "<main><article><p>That's the right answer!</p></article></main>",
+        )]);
+        let client = AocClient::new(&url, "abc");
+
+        let verdict = client.submit(puzzle(7), 2, "1337").unwrap();
+        assert_eq!(verdict, SubmissionVerdict::Correct);
+
+        let request = &server.join().unwrap()[0];
+        assert!(request.starts_with("POST /2024/day/7/answer "));
+        assert!(request.ends_with("level=2&answer=1337"));
+    }
+
+    #[test]
+    fn maps_status_codes_to_errors() {
+        let (url, server) = serve(vec![("404 Not Found", "")]);
+        let client = AocClient::new(&url, "abc");
+        assert!(matches!(
+            client.get_input(puzzle(25)),
+            Err(AocClientError::NotFound)
+        ));
+        server.join().unwrap();
+
+        let (url, server) = serve(vec![("400 Bad Request", "")]);
+        let client = AocClient::new(&url, "abc");
+        assert!(matches!(
+            client.get_input(puzzle(25)),
+            Err(AocClientError::Unauthorized)
+        ));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            SubmissionVerdict::parse("That's not the right answer; your answer is too high. Please wait one minute before trying again."),
+            Some(SubmissionVerdict::Incorrect {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60))
+            })
+        );
+        assert_eq!(
+            SubmissionVerdict::parse("That's not the right answer. Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again."),
+            Some(SubmissionVerdict::Incorrect {
+                hint: None,
+                wait: Some(Duration::from_secs(300))
+            })
+        );
+        assert_eq!(
+            SubmissionVerdict::parse("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 20s left to wait."),
+            Some(SubmissionVerdict::RateLimited {
+                wait: Duration::from_secs(80)
+            })
+        );
+        assert_eq!(SubmissionVerdict::parse("Hello"), None);
+    }
+
+    #[test]
+    fn tells_solved_parts_from_locked_parts() {
+        let wrong_level = "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>";
+        let (url, server) = serve(vec![
+            ("200 OK", wrong_level),
+            ("200 OK", "<p>Your puzzle answer was <code>1</code>.</p>"),
+            ("200 OK", wrong_level),
+            ("200 OK", "<p>Your puzzle answer was <code>1</code>.</p>"),
+        ]);
+        let client = AocClient::new(&url, "abc");
+
+        assert_eq!(
+            client.submit(puzzle(7), 1, "1").unwrap(),
+            SubmissionVerdict::AlreadySolved
+        );
+        assert_eq!(
+            client.submit(puzzle(7), 3, "1").unwrap(),
+            SubmissionVerdict::WrongLevel
+        );
+        server.join().unwrap();
+    }
+}
@@ -1,5 +1,24 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::{
+    registry::Solution,
+    run_multi::run_multi,
+    runner::{BenchConfig, Timeouts},
+    Puzzle, Year,
+};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+pub fn handle(
+    year: Year,
+    is_release: bool,
+    timeouts: &Timeouts,
+    jobs: usize,
+    solutions: Option<&[Solution]>,
+) {
+    run_multi(
+        &year.days().map(|day| Puzzle::new(year, day)).collect(),
+        is_release,
+        false,
+        &BenchConfig::default(),
+        timeouts,
+        jobs,
+        solutions,
+    );
 }
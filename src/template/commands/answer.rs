@@ -0,0 +1,20 @@
+use std::process;
+
+use crate::template::{answers::Answers, Puzzle};
+
+pub fn handle(puzzle: Puzzle, part: u8, answer: &str) {
+    if part != 1 && part != 2 {
+        eprintln!("Unexpected part `{part}`, expecting 1 or 2.");
+        process::exit(1);
+    }
+
+    let mut answers = Answers::read_from_file(puzzle);
+    answers.set(part, answer);
+
+    if let Err(e) = answers.store_file(puzzle) {
+        eprintln!("Failed to store answer: {e}");
+        process::exit(1);
+    }
+
+    println!("🎄 Stored answer \"{answer}\" for {puzzle}, part {part}.");
+}
@@ -1,14 +1,19 @@
-use crate::template::{aoc_cli, Day};
+use crate::template::{aoc_client, Puzzle};
 use std::process;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+pub fn handle(puzzle: Puzzle) {
+    if !try_handle(puzzle) {
         process::exit(1);
     }
+}
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+/// Downloads the input and description of a puzzle, printing the error instead of exiting on failure.
+pub fn try_handle(puzzle: Puzzle) -> bool {
+    match aoc_client::download(puzzle) {
+        Ok(()) => true,
+        Err(e) => {
+            eprintln!("failed to download {puzzle}: {e}");
+            false
+        }
+    }
 }
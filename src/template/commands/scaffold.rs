@@ -1,15 +1,87 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    env,
+    fs::{self, File, OpenOptions},
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
     process,
 };
 
-use crate::template::Day;
+use crate::template::{examples::parse_title, Puzzle};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
-fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
+/// Name of the template that is used if no `--template` is passed.
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+/// Directory of the templates that ship with the repository.
+const TEMPLATES_DIR: &str = "templates";
+
+/// Environment variable that points to a directory of personal templates, searched before `templates/`.
+const TEMPLATES_DIR_ENV: &str = "AOC_TEMPLATES_DIR";
+
+fn template_dirs() -> Vec<PathBuf> {
+    env::var_os(TEMPLATES_DIR_ENV)
+        .map(PathBuf::from)
+        .into_iter()
+        .chain([PathBuf::from(TEMPLATES_DIR)])
+        .collect()
+}
+
+/// Reads the template `<name>.txt` from the template directories.
+/// The default template falls back to `src/template.txt` if no directory overrides it.
+fn read_template(name: &str) -> Result<String, String> {
+    let dirs = template_dirs();
+
+    for dir in &dirs {
+        match fs::read_to_string(dir.join(format!("{name}.txt"))) {
+            Ok(template) => return Ok(template),
+            Err(e) if e.kind() == ErrorKind::NotFound => {}
+            Err(e) => return Err(format!("Failed to read template \"{name}\": {e}")),
+        }
+    }
+
+    if name == DEFAULT_TEMPLATE {
+        return Ok(MODULE_TEMPLATE.to_string());
+    }
+
+    let mut available: Vec<String> = dirs
+        .iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            name.strip_suffix(".txt").map(String::from)
+        })
+        .chain([DEFAULT_TEMPLATE.to_string()])
+        .collect();
+    available.sort_unstable();
+    available.dedup();
+
+    Err(format!(
+        "Unknown template \"{name}\", available templates: {}",
+        available.join(", ")
+    ))
+}
+
+/// Reads the title from the puzzle description if it was downloaded, falls back to e.g. `Day 8`.
+fn read_title(puzzle: Puzzle) -> String {
+    fs::read_to_string(puzzle.data_path("puzzles", "md"))
+        .ok()
+        .and_then(|markdown| parse_title(&markdown))
+        .unwrap_or_else(|| format!("Day {}", puzzle.day.into_inner()))
+}
+
+fn render_template(template: &str, puzzle: Puzzle) -> String {
+    template
+        .replace("%DAY_NUMBER%", &puzzle.day.into_inner().to_string())
+        .replace("%DAY_PADDED%", &puzzle.day.to_string())
+        .replace("%YEAR%", &puzzle.year.to_string())
+        .replace("%TITLE%", &read_title(puzzle))
+}
+
+fn safe_create_file(path: &Path, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
     if overwrite {
         file.create(true);
@@ -19,20 +91,29 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Creates an empty file, keeping the contents of an existing one, e.g. an input that was downloaded before.
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+pub fn handle(puzzle: Puzzle, template: &str, overwrite: bool) {
+    let input_path = puzzle.data_path("inputs", "txt");
+    let example_path = puzzle.data_path("examples", "txt");
+    let module_path = puzzle.bin_path();
+    let module_path = Path::new(&module_path);
+
+    let template = match read_template(template) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
+    let mut file = match safe_create_file(module_path, overwrite) {
         Ok(file) => file,
         Err(e) => {
             eprintln!("Failed to create module file: {e}");
@@ -40,13 +121,9 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(render_template(&template, puzzle).as_bytes()) {
         Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created module file \"{}\"", module_path.display());
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {e}");
@@ -56,8 +133,9 @@ pub fn handle(day: Day, overwrite: bool) {
 
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty input file \"{}\"", input_path.display());
         }
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => {}
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
             process::exit(1);
@@ -66,8 +144,9 @@ pub fn handle(day: Day, overwrite: bool) {
 
     match create_file(&example_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty example file \"{}\"", example_path.display());
         }
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => {}
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
             process::exit(1);
@@ -75,5 +154,8 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!(
+        "🎄 Type `cargo solve {} --year {}` to run your solution.",
+        puzzle.day, puzzle.year
+    );
 }
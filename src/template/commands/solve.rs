@@ -1,9 +1,17 @@
+use std::env;
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::{alloc, profiler, Puzzle};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(
+    puzzle: Puzzle,
+    release: bool,
+    dhat: bool,
+    allocations: bool,
+    profile: bool,
+    submit_part: Option<u8>,
+) {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.bin_name()];
 
     if dhat {
         cmd_args.extend([
@@ -12,23 +20,48 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
             "--features".to_string(),
             "dhat-heap".to_string(),
         ]);
+    } else if profile {
+        // release build with debug info, so inlined functions show up in the flamegraph.
+        cmd_args.extend([
+            "--profile".to_string(),
+            "profiling".to_string(),
+            "--features".to_string(),
+            profiler::FEATURE.to_string(),
+        ]);
     } else if release {
         cmd_args.push("--release".to_string());
     }
 
+    if allocations {
+        cmd_args.extend(["--features".to_string(), alloc::FEATURE.to_string()]);
+    }
+
     cmd_args.push("--".to_string());
 
+    if profile {
+        cmd_args.push("--profile".to_string());
+    }
+
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
     }
 
-    let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
+    let mut cmd = Command::new("cargo");
+    cmd.args(&cmd_args)
         .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
+        .stderr(Stdio::inherit());
+
+    if profile {
+        // the sampler walks frame pointers, which release builds omit by default.
+        let rustflags = env::var("RUSTFLAGS").unwrap_or_default();
+        cmd.env(
+            "RUSTFLAGS",
+            format!("{rustflags} -C force-frame-pointers=yes").trim_start(),
+        );
+    }
+
+    let mut cmd = cmd.spawn().unwrap();
 
     cmd.wait().unwrap();
 }
@@ -0,0 +1,130 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Write},
+    process::{self, Command, Stdio},
+};
+
+use crate::template::{
+    examples::{parse_examples, PuzzleExamples},
+    run_multi::child_commands,
+    Puzzle, ANSI_BOLD, ANSI_RESET,
+};
+
+/// Writes the extracted examples. Existing files are kept unless `overwrite` is set, so manual fixes survive.
+/// NOTE: empty files are replaced, since `scaffold` creates an empty example file.
+fn write_examples(puzzle: Puzzle, examples: &PuzzleExamples, overwrite: bool) {
+    let paths = [
+        (&examples.part_1, puzzle.data_path("examples", "txt")),
+        (
+            &examples.part_2,
+            puzzle
+                .data_dir("examples")
+                .join(format!("{}-2.txt", puzzle.day)),
+        ),
+    ];
+
+    if let Err(e) = fs::create_dir_all(puzzle.data_dir("examples")) {
+        eprintln!("Failed to create examples directory: {e}");
+        process::exit(1);
+    }
+
+    for (example, path) in paths {
+        let Some(input) = &example.input else {
+            continue;
+        };
+
+        let is_empty = fs::metadata(&path).is_ok_and(|metadata| metadata.len() == 0);
+        let result = if overwrite || is_empty {
+            fs::write(&path, input)
+        } else {
+            OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+                .and_then(|mut file| file.write_all(input.as_bytes()))
+        };
+
+        match result {
+            Ok(()) => println!("Wrote example input to \"{}\"", path.display()),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => println!(
+                "Kept existing example input \"{}\", pass `--overwrite` to extract it again",
+                path.display()
+            ),
+            Err(e) => {
+                eprintln!("Failed to write example file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
+
+pub fn handle(puzzle: Puzzle, overwrite: bool) {
+    let puzzle_path = puzzle.data_path("puzzles", "md");
+
+    let Ok(markdown) = fs::read_to_string(&puzzle_path) else {
+        eprintln!(
+            "Could not read \"{}\". Try running `cargo download {} --year {}` first.",
+            puzzle_path.display(),
+            puzzle.day,
+            puzzle.year
+        );
+        process::exit(1);
+    };
+
+    let examples = parse_examples(&markdown);
+    write_examples(puzzle, &examples, overwrite);
+
+    println!();
+    println!("{ANSI_BOLD}{} (examples){ANSI_RESET}", puzzle.bin_name());
+    println!("------");
+
+    let reports = match child_commands::run_example(puzzle) {
+        Ok(reports) => reports,
+        Err(e) => {
+            eprintln!("Failed to run solution: {e:?}");
+            process::exit(1);
+        }
+    };
+
+    let mut passed = true;
+
+    println!();
+    for report in &reports {
+        let expected = examples
+            .get(report.part)
+            .and_then(|example| example.answer.as_deref());
+
+        match (expected, report.answer.as_deref()) {
+            (None, _) => println!("Part {}: no expected answer found.", report.part),
+            (Some(expected), Some(answer)) if expected == answer => {
+                println!("Part {}: ✔ {answer}", report.part);
+            }
+            (Some(expected), answer) => {
+                passed = false;
+                println!(
+                    "Part {}: ✖ expected {expected}, got {}",
+                    report.part,
+                    answer.unwrap_or("nothing")
+                );
+            }
+        }
+    }
+
+    println!();
+    let tests_passed = Command::new("cargo")
+        .args(["test", "--quiet", "--bin", &puzzle.bin_name()])
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+        .is_ok_and(|status| status.success());
+
+    if tests_passed {
+        println!("Tests: ✔");
+    } else {
+        println!("Tests: ✖");
+    }
+
+    if !(passed && tests_passed) {
+        process::exit(1);
+    }
+}
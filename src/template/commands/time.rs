@@ -1,29 +1,108 @@
 use std::collections::HashSet;
 
+use crate::template::history::{self, History, HistoryRecord};
+use crate::template::registry::Solution;
 use crate::template::run_multi::run_multi;
+use crate::template::runner::{BenchConfig, Timeouts};
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::{readme_benchmarks, Day, Puzzle, Year, ANSI_BOLD, ANSI_RESET};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
+/// Options for comparing a run against the timing history.
+pub struct CompareOptions {
+    /// Compare against runs of this git revision. Uses the most recent runs if `None`.
+    pub rev: Option<String>,
+    /// Slowdowns above this percentage are flagged as regressions.
+    pub threshold_percent: f64,
+}
+
+fn print_comparison(history: &History, timings: &Timings, options: &CompareOptions) {
+    let commit = options
+        .rev
+        .as_deref()
+        .map(|rev| history::resolve_commit(rev).unwrap_or_else(|| rev.to_string()));
+
+    let baseline = history.baseline(commit.as_deref());
+    let deltas = history::compare(&baseline, timings);
+
+    let label = options.rev.as_deref().unwrap_or("latest stored run");
+
+    println!();
+    println!(
+        "{ANSI_BOLD}Comparison against {label}{ANSI_RESET} (threshold: {}%)",
+        options.threshold_percent
+    );
+
+    if deltas.is_empty() {
+        println!("No stored timings to compare against.");
+        return;
+    }
+
+    for delta in &deltas {
+        let flag = if delta.is_regression(options.threshold_percent) {
+            format!(" {ANSI_BOLD}✖ regression{ANSI_RESET}")
+        } else {
+            String::new()
+        };
+
+        println!(
+            "{} Part {}: {:.1?} → {:.1?} ({:+.1}%){flag}",
+            delta.puzzle.bin_name(),
+            delta.part,
+            delta.before,
+            delta.after,
+            delta.change_percent()
+        );
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: Year,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    bench_config: &BenchConfig,
+    timeouts: &Timeouts,
+    solutions: Option<&[Solution]>,
+    compare: Option<&CompareOptions>,
+) {
     let stored_timings = Timings::read_from_file();
 
-    let days_to_run = day.map_or_else(
+    let puzzles_to_run = day.map_or_else(
         || {
+            let puzzles = year.days().map(|day| Puzzle::new(year, day));
             if run_all {
-                all_days().collect()
+                puzzles.collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                puzzles
+                    .filter(|puzzle| !stored_timings.is_day_complete(*puzzle))
                     .collect()
             }
         },
-        |day| HashSet::from([day]),
+        |day| HashSet::from([Puzzle::new(year, day)]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let timings = run_multi(
+        &puzzles_to_run,
+        true,
+        true,
+        bench_config,
+        timeouts,
+        1,
+        solutions,
+    )
+    .unwrap();
+
+    if let Some(options) = compare {
+        print_comparison(&History::read_from_file(), &timings, options);
+    }
 
     if store {
+        if let Err(e) = History::append_to_file(&HistoryRecord::new(timings.clone())) {
+            eprintln!("Failed to append to timing history: {e}");
+        }
+
         let merged_timings = stored_timings.merge(&timings);
         merged_timings.store_file().unwrap();
 
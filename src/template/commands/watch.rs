@@ -0,0 +1,160 @@
+/// Re-runs the tests and the solution of a puzzle whenever one of its files changes.
+/// Files are polled, so this works without `cargo-watch` or platform specific file system events.
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::{Path, PathBuf},
+    process::{self, Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{
+    report::PartReport,
+    run_multi::child_commands::{self, OutputSink},
+    runner::{BenchConfig, Timeouts},
+    Puzzle, ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET, ANSI_YELLOW,
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Modification time and length of every watched file.
+type Snapshot = BTreeMap<PathBuf, (SystemTime, u64)>;
+
+fn collect_files(path: &Path, files: &mut Snapshot) {
+    let Ok(metadata) = fs::metadata(path) else {
+        return;
+    };
+
+    if metadata.is_dir() {
+        if let Ok(entries) = fs::read_dir(path) {
+            for entry in entries.filter_map(Result::ok) {
+                collect_files(&entry.path(), files);
+            }
+        }
+    } else if let Ok(modified) = metadata.modified() {
+        files.insert(path.to_path_buf(), (modified, metadata.len()));
+    }
+}
+
+/// Takes a snapshot of the solution, `src/utils/**` and the example files of the puzzle.
+fn snapshot(puzzle: Puzzle) -> Snapshot {
+    let mut files = Snapshot::new();
+
+    collect_files(Path::new(&puzzle.bin_path()), &mut files);
+    collect_files(Path::new("src/utils"), &mut files);
+
+    // NOTE: matches `08.txt` as well as part-specific examples like `08-2.txt` and params like `08.params`.
+    if let Ok(entries) = fs::read_dir(puzzle.data_dir("examples")) {
+        entries
+            .filter_map(Result::ok)
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .to_string_lossy()
+                    .starts_with(&puzzle.day.to_string())
+            })
+            .for_each(|entry| collect_files(&entry.path(), &mut files));
+    }
+
+    files
+}
+
+fn run_cargo(args: &[&str]) -> bool {
+    Command::new("cargo")
+        .args(args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// Prints the answer of every part next to the answer of the previous run.
+fn print_answer_diff(previous: &HashMap<u8, Option<String>>, reports: &[PartReport]) {
+    for report in reports {
+        let answer = report.answer.as_deref().unwrap_or("-");
+
+        match previous.get(&report.part) {
+            None => println!("Part {}: {answer}", report.part),
+            Some(before) if before.as_deref() == report.answer.as_deref() => {
+                println!("Part {}: {answer} (unchanged)", report.part);
+            }
+            Some(before) => println!(
+                "Part {}: {ANSI_RED}{}{ANSI_RESET} → {ANSI_GREEN}{answer}{ANSI_RESET}",
+                report.part,
+                before.as_deref().unwrap_or("-")
+            ),
+        }
+    }
+}
+
+pub fn handle(puzzle: Puzzle, release: bool) {
+    let bin_name = puzzle.bin_name();
+
+    if !Path::new(&puzzle.bin_path()).exists() {
+        eprintln!(
+            "Could not find \"{}\". Try running `cargo scaffold {} --year {}` first.",
+            puzzle.bin_path(),
+            puzzle.day,
+            puzzle.year
+        );
+        process::exit(1);
+    }
+
+    let mut build_args = vec!["build", "--quiet", "--bin", &bin_name];
+    let mut test_args = vec!["test", "--quiet", "--bin", &bin_name];
+    if release {
+        build_args.push("--release");
+        test_args.push("--release");
+    }
+
+    let mut previous_answers: HashMap<u8, Option<String>> = HashMap::new();
+    let mut previous_snapshot: Option<Snapshot> = None;
+
+    println!("🎄 Watching {puzzle}. Press Ctrl+C to stop.");
+
+    loop {
+        let current_snapshot = snapshot(puzzle);
+
+        if previous_snapshot.as_ref() == Some(&current_snapshot) {
+            thread::sleep(POLL_INTERVAL);
+            continue;
+        }
+
+        previous_snapshot = Some(current_snapshot);
+
+        println!();
+        println!("{ANSI_BOLD}{}{ANSI_RESET}", puzzle.bin_name());
+        println!("------");
+
+        if !run_cargo(&build_args) {
+            println!("{ANSI_RED}Build: ✖{ANSI_RESET}");
+            continue;
+        }
+
+        if run_cargo(&test_args) {
+            println!("{ANSI_GREEN}Tests: ✔{ANSI_RESET}");
+        } else {
+            println!("{ANSI_RED}Tests: ✖{ANSI_RESET}");
+        }
+
+        match child_commands::run_solution(
+            puzzle,
+            false,
+            release,
+            &BenchConfig::default(),
+            &Timeouts::default(),
+            &OutputSink::Inherit,
+        ) {
+            Ok(reports) => {
+                println!();
+                print_answer_diff(&previous_answers, &reports);
+                previous_answers = reports
+                    .into_iter()
+                    .map(|report| (report.part, report.answer))
+                    .collect();
+            }
+            Err(e) => eprintln!("{ANSI_YELLOW}Failed to run solution: {e:?}{ANSI_RESET}"),
+        }
+    }
+}
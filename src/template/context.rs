@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Information about the input a solution part runs against.
+///
+/// Parameters are read from a front matter header at the top of the input file and from a sidecar `.params` file next to it, e.g. `data/2024/examples/14.params`.
+/// Both use one `key: value` pair per line. Keys in the front matter take precedence.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Context {
+    /// Whether the input is an example input.
+    pub example: bool,
+    pub params: HashMap<String, String>,
+}
+
+/// Delimits the front matter header of an input file.
+const FRONT_MATTER_DELIMITER: &str = "---";
+
+impl Context {
+    /// Splits the front matter off `content` and collects the parameters of both the front matter and `sidecar`.
+    #[must_use]
+    pub fn parse(content: &str, sidecar: &str, example: bool) -> (String, Self) {
+        let mut params = parse_params(sidecar);
+
+        let input = match split_front_matter(content) {
+            Some((front_matter, input)) => {
+                params.extend(parse_params(front_matter));
+                input
+            }
+            None => content,
+        };
+
+        (input.to_string(), Self { example, params })
+    }
+
+    /// Reads an input file and its sidecar `.params` file, if present.
+    pub fn read(path: &Path, example: bool) -> std::io::Result<(String, Self)> {
+        let content = std::fs::read_to_string(path)?;
+        let sidecar = std::fs::read_to_string(path.with_extension("params")).unwrap_or_default();
+        Ok(Self::parse(&content, &sidecar, example))
+    }
+
+    /// Returns a parameter parsed as `T`, or `None` if it is missing or can't be parsed.
+    pub fn param<T: FromStr>(&self, key: &str) -> Option<T> {
+        self.params.get(key).and_then(|x| x.parse().ok())
+    }
+
+    /// Returns a parameter parsed as `T`, or `default` if it is missing or can't be parsed.
+    pub fn param_or<T: FromStr>(&self, key: &str, default: T) -> T {
+        self.param(key).unwrap_or(default)
+    }
+}
+
+/// Returns the front matter and the remaining content, if `content` starts with a front matter header.
+fn split_front_matter(content: &str) -> Option<(&str, &str)> {
+    let rest = content
+        .strip_prefix(FRONT_MATTER_DELIMITER)?
+        .strip_prefix('\n')
+        .or_else(|| content.strip_prefix("---\r\n"))?;
+
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == FRONT_MATTER_DELIMITER {
+            return Some((&rest[..offset], &rest[offset + line.len()..]));
+        }
+        offset += line.len();
+    }
+
+    None
+}
+
+fn parse_params(text: &str) -> HashMap<String, String> {
+    text.lines()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .filter(|(key, _)| !key.is_empty() && !key.starts_with('#'))
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Context;
+
+    #[test]
+    fn parses_front_matter() {
+        let (input, context) = Context::parse("---\nsteps: 6\n---\n...\n.#.\n", "", true);
+        assert_eq!(input, "...\n.#.\n");
+        assert!(context.example);
+        assert_eq!(context.param::<u32>("steps"), Some(6));
+    }
+
+    #[test]
+    fn falls_back_to_defaults() {
+        let (input, context) = Context::parse("---\nwidth: 11\n---\np=0,4 v=3,-3\n", "", true);
+        assert_eq!(input, "p=0,4 v=3,-3\n");
+        assert_eq!(context.param_or("width", 101), 11);
+        assert_eq!(context.param_or("height", 103), 103);
+    }
+
+    #[test]
+    fn merges_sidecar_params() {
+        let (_, context) = Context::parse("---\nwidth: 11\n---\n", "width: 5\nheight: 7\n", false);
+        assert_eq!(context.param::<u32>("width"), Some(11));
+        assert_eq!(context.param::<u32>("height"), Some(7));
+    }
+
+    #[test]
+    fn keeps_input_without_front_matter() {
+        let input = "---\nnot closed\n";
+        assert_eq!(Context::parse(input, "", false).0, input);
+        assert_eq!(Context::parse("1 2\n", "", false).0, "1 2\n");
+    }
+
+    #[test]
+    fn handles_unparsable_params() {
+        let (_, context) = Context::parse("", "width: wide", false);
+        assert_eq!(context.param::<u32>("width"), None);
+        assert_eq!(context.param_or("width", 101), 101);
+        assert_eq!(context.param::<String>("width"), Some("wide".into()));
+    }
+}
@@ -0,0 +1,108 @@
+use std::error::Error;
+use std::fmt::Display;
+
+/// An error returned by a solution part, optionally pointing at a position in the input.
+///
+/// Parts may return `Result<T, E>` with any `E: Display`, this type is a convenience for errors caused by malformed input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolutionError {
+    pub message: String,
+    /// 1-based line and column in the input.
+    pub position: Option<(usize, usize)>,
+}
+
+impl SolutionError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            position: None,
+        }
+    }
+
+    /// Attaches a 1-based line and column to the error.
+    #[must_use]
+    pub fn at(mut self, line: usize, column: usize) -> Self {
+        self.position = Some((line, column));
+        self
+    }
+
+    /// Attaches the position of a byte offset in `input` to the error.
+    /// An offset inside a multi-byte character points at that character.
+    #[must_use]
+    pub fn at_offset(self, input: &str, offset: usize) -> Self {
+        let mut end = offset.min(input.len());
+        while !input.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        let before = &input[..end];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rfind('\n').map_or(before, |i| &before[i + 1..]);
+        self.at(line, column.chars().count() + 1)
+    }
+}
+
+impl Display for SolutionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.position {
+            Some((line, column)) => write!(f, "{} (line {line}, column {column})", self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl Error for SolutionError {}
+
+impl From<&str> for SolutionError {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for SolutionError {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::SolutionError;
+
+    #[test]
+    fn formats_errors() {
+        assert_eq!(SolutionError::new("oops").to_string(), "oops");
+        assert_eq!(
+            SolutionError::new("oops").at(3, 4).to_string(),
+            "oops (line 3, column 4)"
+        );
+    }
+
+    #[test]
+    fn formats_errors_at_offsets() {
+        let input = "1 2\n3 x\n";
+        let error = SolutionError::new("expected a number").at_offset(input, 6);
+        assert_eq!(error.to_string(), "expected a number (line 2, column 3)");
+    }
+
+    #[test]
+    fn resolves_offsets() {
+        let input = "abc\ndef\n";
+        assert_eq!(
+            SolutionError::new("").at_offset(input, 0).position,
+            Some((1, 1))
+        );
+        assert_eq!(
+            SolutionError::new("").at_offset(input, 5).position,
+            Some((2, 2))
+        );
+        assert_eq!(
+            SolutionError::new("").at_offset(input, 100).position,
+            Some((3, 1))
+        );
+        assert_eq!(
+            SolutionError::new("").at_offset("aä\n", 2).position,
+            Some((1, 2))
+        );
+    }
+}
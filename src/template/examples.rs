@@ -0,0 +1,230 @@
+//! Extracts example inputs and expected answers from a puzzle description, as written by `cargo download`.
+//!
+//! Heuristics:
+//!  1. the description is split into parts at the `--- Part Two ---` header.
+//!  2. the example input of a part is the first code block introduced by a paragraph mentioning an "example".
+//!     Part one falls back to its first code block.
+//!  3. the expected answer of a part is the last highlighted inline code span, e.g. `` `*42*` `` or ``*`42`*``.
+
+/// The example of a single part.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Example {
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+/// The examples of both parts of a puzzle.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PuzzleExamples {
+    pub part_1: Example,
+    pub part_2: Example,
+}
+
+impl PuzzleExamples {
+    pub fn get(&self, part: u8) -> Option<&Example> {
+        match part {
+            1 => Some(&self.part_1),
+            2 => Some(&self.part_2),
+            _ => None,
+        }
+    }
+}
+
+/// Reads the title of a puzzle from its description, e.g. `Historian Hysteria`.
+/// Handles the `## --- Day 1: Title ---` headers written by `cargo download` and the `\--- Day 1: Title ---` headers of `aoc-cli`.
+#[must_use]
+pub fn parse_title(markdown: &str) -> Option<String> {
+    markdown.lines().find_map(|line| {
+        let line = line.trim_start_matches('#').trim_start();
+        let line = line.strip_prefix('\\').unwrap_or(line);
+        let (_, title) = line
+            .strip_prefix("--- Day ")?
+            .strip_suffix("---")?
+            .split_once(':')?;
+        let title = title.trim();
+        (!title.is_empty()).then(|| title.to_string())
+    })
+}
+
+#[must_use]
+pub fn parse_examples(markdown: &str) -> PuzzleExamples {
+    let (part_1, part_2) = match markdown.find("--- Part Two ---") {
+        Some(index) => markdown.split_at(index),
+        None => (markdown, ""),
+    };
+
+    PuzzleExamples {
+        part_1: parse_part(part_1, true),
+        part_2: parse_part(part_2, false),
+    }
+}
+
+fn parse_part(text: &str, fallback_to_first_block: bool) -> Example {
+    let mut blocks: Vec<(String, bool)> = vec![];
+    let mut prose = String::new();
+
+    let mut current_block: Option<Vec<&str>> = None;
+    let mut recent_lines: Vec<&str> = vec![];
+
+    for line in text.lines() {
+        if line.trim_start().starts_with("```") {
+            match current_block.take() {
+                Some(lines) => {
+                    let introduced_by_example = recent_lines
+                        .iter()
+                        .any(|l| l.to_lowercase().contains("example"));
+                    blocks.push((lines.join("\n") + "\n", introduced_by_example));
+                    recent_lines.clear();
+                }
+                None => current_block = Some(vec![]),
+            }
+            continue;
+        }
+
+        match current_block.as_mut() {
+            Some(lines) => lines.push(line),
+            None => {
+                prose.push_str(line);
+                prose.push('\n');
+
+                if !line.trim().is_empty() {
+                    recent_lines.push(line);
+                    if recent_lines.len() > 3 {
+                        recent_lines.remove(0);
+                    }
+                }
+            }
+        }
+    }
+
+    let input = blocks
+        .iter()
+        .find(|(_, introduced_by_example)| *introduced_by_example)
+        .or_else(|| blocks.first().filter(|_| fallback_to_first_block))
+        .map(|(block, _)| block.clone());
+
+    Example {
+        input,
+        answer: highlighted_spans(&prose).pop(),
+    }
+}
+
+/// Finds inline code spans that are highlighted, i.e. `` `*x*` ``, ``*`x`*`` or their bold variants.
+fn highlighted_spans(text: &str) -> Vec<String> {
+    let mut spans = vec![];
+    let mut rest = text;
+
+    while let Some(start) = rest.find('`') {
+        let Some(len) = rest[start + 1..].find('`') else {
+            break;
+        };
+
+        let end = start + 1 + len;
+        let content = &rest[start + 1..end];
+
+        let emphasized_inside =
+            content.len() > 2 && content.starts_with('*') && content.ends_with('*');
+        let emphasized_outside = rest[..start].ends_with('*') && rest[end + 1..].starts_with('*');
+
+        if emphasized_inside || emphasized_outside {
+            let value = content.trim_matches('*').trim();
+            if !value.is_empty() {
+                spans.push(value.to_string());
+            }
+        }
+
+        rest = &rest[end + 1..];
+    }
+
+    spans
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_examples, parse_title, Example};
+
+    const PUZZLE: &str = "\\--- Day 1: Historian Hysteria ---
+----------
+
+Some intro text with `code` in it.
+
+For example:
+
+```
+3   4
+4   3
+```
+
+Maybe the lists are only off by a small amount!
+
+```
+1 2
+```
+
+To find the total distance, add up all of them. In the example above, this is `2 + 1 + 0 + 1 + 2 + 5`, a total distance of `*11*`!
+
+\\--- Part Two ---
+----------
+
+This time, you'll need to figure out exactly how often each number appears.
+
+```
+3 appears 3 times
+```
+
+So, for these example lists, the similarity score at the end of this process is *`31`*.
+";
+
+    #[test]
+    fn parses_examples() {
+        let examples = parse_examples(PUZZLE);
+        assert_eq!(
+            examples.part_1,
+            Example {
+                input: Some("3   4\n4   3\n".into()),
+                answer: Some("11".into()),
+            }
+        );
+        assert_eq!(
+            examples.part_2,
+            Example {
+                input: None,
+                answer: Some("31".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_part_specific_examples() {
+        let puzzle = PUZZLE.replace(
+            "This time, you'll need",
+            "Here is a larger example:\n\n```\n1 1\n```\n\nThis time, you'll need",
+        );
+        let examples = parse_examples(&puzzle);
+        assert_eq!(examples.part_2.input, Some("1 1\n".into()));
+    }
+
+    #[test]
+    fn handles_unsolved_part_one() {
+        let examples = parse_examples(PUZZLE.split("\\--- Part Two").next().unwrap());
+        assert_eq!(examples.part_1.answer, Some("11".into()));
+        assert_eq!(examples.part_2, Example::default());
+    }
+
+    #[test]
+    fn falls_back_to_first_block() {
+        let examples = parse_examples("```\nfoo\n```\nResult is **`7`**.");
+        assert_eq!(examples.part_1.input, Some("foo\n".into()));
+        assert_eq!(examples.part_1.answer, Some("7".into()));
+    }
+
+    #[test]
+    fn parses_titles() {
+        assert_eq!(parse_title(PUZZLE), Some("Historian Hysteria".into()));
+        assert_eq!(
+            parse_title("## --- Day 8: Resonant Collinearity ---\n\nText"),
+            Some("Resonant Collinearity".into())
+        );
+        assert_eq!(parse_title("## --- Part Two ---"), None);
+    }
+}
@@ -0,0 +1,365 @@
+/// Module that keeps a history of stored benchmark runs and compares runs against it.
+/// Every `cargo time --store` appends one record to a JSON lines file, tagged with the time, git commit and hostname.
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::{Error, Write},
+    process::Command,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{timings::Timings, Puzzle};
+
+static HISTORY_FILE_PATH: &str = "./data/timings-history.jsonl";
+
+/// A single stored benchmark run.
+#[derive(Clone, Debug)]
+pub struct HistoryRecord {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub commit: Option<String>,
+    pub hostname: Option<String>,
+    pub timings: Timings,
+}
+
+impl HistoryRecord {
+    /// Creates a record for the current commit and host.
+    pub fn new(timings: Timings) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        Self {
+            timestamp,
+            commit: resolve_commit("HEAD"),
+            hostname: get_hostname(),
+            timings,
+        }
+    }
+}
+
+/// All stored benchmark runs, oldest first.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    pub records: Vec<HistoryRecord>,
+}
+
+impl History {
+    /// Rehydrate the history from its JSON lines file. If not present, returns an empty history.
+    pub fn read_from_file() -> Self {
+        let Ok(content) = fs::read_to_string(HISTORY_FILE_PATH) else {
+            return Self::default();
+        };
+
+        let records = content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| match HistoryRecord::try_from(line) {
+                Ok(record) => Some(record),
+                Err(e) => {
+                    eprintln!("Skipping malformed timing history record: {e}");
+                    None
+                }
+            })
+            .collect();
+
+        Self { records }
+    }
+
+    /// Append a record to the JSON lines file.
+    pub fn append_to_file(record: &HistoryRecord) -> Result<(), Error> {
+        let line = JsonValue::from(record)
+            .stringify()
+            .map_err(|e| Error::other(e.to_string()))?;
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(HISTORY_FILE_PATH)?;
+
+        writeln!(file, "{line}")
+    }
+
+    /// Collects the most recent timing of every puzzle, optionally restricted to runs of a commit.
+    /// `commit` may be a full hash or a prefix of one.
+    pub fn baseline(&self, commit: Option<&str>) -> Timings {
+        let records: Vec<&HistoryRecord> = self
+            .records
+            .iter()
+            .filter(|record| match (commit, &record.commit) {
+                (None, _) => true,
+                (Some(commit), Some(record_commit)) => record_commit.starts_with(commit),
+                (Some(_), None) => false,
+            })
+            .collect();
+
+        let mut puzzles: Vec<Puzzle> = records
+            .iter()
+            .flat_map(|record| record.timings.data.iter().map(|t| t.puzzle))
+            .collect();
+        puzzles.sort_unstable();
+        puzzles.dedup();
+
+        let data = puzzles
+            .into_iter()
+            .filter_map(|puzzle| {
+                records
+                    .iter()
+                    .rev()
+                    .find_map(|record| record.timings.data.iter().find(|t| t.puzzle == puzzle))
+                    .cloned()
+            })
+            .collect();
+
+        Timings { data }
+    }
+}
+
+/// The change in mean execution time of a single part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PartDelta {
+    pub puzzle: Puzzle,
+    pub part: u8,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl PartDelta {
+    /// Relative change in percent. Positive values mean the part got slower.
+    pub fn change_percent(&self) -> f64 {
+        let before = self.before.as_nanos() as f64;
+        let after = self.after.as_nanos() as f64;
+        if before == 0.0 {
+            return 0.0;
+        }
+        (after - before) / before * 100.0
+    }
+
+    pub fn is_regression(&self, threshold_percent: f64) -> bool {
+        self.change_percent() > threshold_percent
+    }
+}
+
+/// Compares every benched part of `current` against the same part in `baseline`.
+pub fn compare(baseline: &Timings, current: &Timings) -> Vec<PartDelta> {
+    current
+        .data
+        .iter()
+        .flat_map(|timing| [(timing.puzzle, 1), (timing.puzzle, 2)])
+        .filter_map(|(puzzle, part)| {
+            Some(PartDelta {
+                puzzle,
+                part,
+                before: baseline.part_stats(puzzle, part)?.mean,
+                after: current.part_stats(puzzle, part)?.mean,
+            })
+        })
+        .collect()
+}
+
+/// Resolves a git revision to a commit hash.
+pub fn resolve_commit(rev: &str) -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--verify", &format!("{rev}^{{commit}}")])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let commit = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!commit.is_empty()).then_some(commit)
+}
+
+fn get_hostname() -> Option<String> {
+    let hostname = std::env::var("HOSTNAME")
+        .ok()
+        .or_else(|| fs::read_to_string("/etc/hostname").ok())
+        .or_else(|| {
+            let output = Command::new("hostname").output().ok()?;
+            Some(String::from_utf8_lossy(&output.stdout).to_string())
+        })?;
+
+    let hostname = hostname.trim();
+    (!hostname.is_empty()).then(|| hostname.to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryRecord> for JsonValue {
+    fn from(value: &HistoryRecord) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "commit".into(),
+            value
+                .commit
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "hostname".into(),
+            value
+                .hostname
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert("timings".into(), JsonValue::from(value.timings.clone()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&str> for HistoryRecord {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(value).or(Err("not valid JSON."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected record to be an object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64)
+            .ok_or("expected record.timestamp to be a number.")?;
+
+        let optional_string = |key: &str| json.get(key).and_then(|v| v.get::<String>()).cloned();
+
+        let timings = json
+            .get("timings")
+            .ok_or("expected record to have key `timings`.")
+            .map(Timings::try_from)??;
+
+        Ok(HistoryRecord {
+            timestamp,
+            commit: optional_string("commit"),
+            hostname: optional_string("hostname"),
+            timings,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{compare, History, HistoryRecord, PartDelta};
+    use crate::{
+        day,
+        template::{
+            runner::BenchStats,
+            timings::{Timing, Timings},
+            Puzzle,
+        },
+        year,
+    };
+    use tinyjson::JsonValue;
+
+    fn get_mock_timings(day: u8, part_1_millis: u64) -> Timings {
+        let stats = BenchStats::from_samples(&[Duration::from_millis(part_1_millis)]);
+        Timings {
+            data: vec![Timing {
+                puzzle: Puzzle::new(year!(2024), crate::template::Day::new(day).unwrap()),
+                part_1: Some(format!("{part_1_millis}ms")),
+                part_2: None,
+                part_1_stats: Some(stats),
+                part_2_stats: None,
+                part_1_status: None,
+                part_2_status: None,
+                part_1_alloc: None,
+                part_2_alloc: None,
+                total_nanos: stats.mean.as_nanos() as f64,
+            }],
+        }
+    }
+
+    fn get_mock_record(commit: &str, day: u8, part_1_millis: u64) -> HistoryRecord {
+        HistoryRecord {
+            timestamp: 1_700_000_000,
+            commit: Some(commit.into()),
+            hostname: Some("localhost".into()),
+            timings: get_mock_timings(day, part_1_millis),
+        }
+    }
+
+    #[test]
+    fn roundtrips_records() {
+        let record = get_mock_record("abcdef", 11, 10);
+        let line = JsonValue::from(&record).stringify().unwrap();
+        let parsed = HistoryRecord::try_from(line.as_str()).unwrap();
+        assert_eq!(parsed.timestamp, record.timestamp);
+        assert_eq!(parsed.commit, record.commit);
+        assert_eq!(parsed.hostname, record.hostname);
+        assert_eq!(parsed.timings.data.len(), 1);
+        assert_eq!(
+            parsed.timings.data[0].part_1_stats,
+            record.timings.data[0].part_1_stats
+        );
+    }
+
+    #[test]
+    fn finds_latest_baseline() {
+        let history = History {
+            records: vec![
+                get_mock_record("aaaa", 11, 10),
+                get_mock_record("bbbb", 11, 20),
+                get_mock_record("bbbb", 21, 30),
+            ],
+        };
+
+        let baseline = history.baseline(None);
+        assert_eq!(baseline.data.len(), 2);
+        assert_eq!(
+            baseline
+                .part_stats(Puzzle::new(year!(2024), day!(11)), 1)
+                .unwrap()
+                .mean,
+            Duration::from_millis(20)
+        );
+
+        let baseline = history.baseline(Some("aa"));
+        assert_eq!(baseline.data.len(), 1);
+        assert_eq!(
+            baseline
+                .part_stats(Puzzle::new(year!(2024), day!(11)), 1)
+                .unwrap()
+                .mean,
+            Duration::from_millis(10)
+        );
+    }
+
+    #[test]
+    fn compares_timings() {
+        let deltas = compare(&get_mock_timings(11, 10), &get_mock_timings(11, 12));
+        assert_eq!(
+            deltas,
+            vec![PartDelta {
+                puzzle: Puzzle::new(year!(2024), day!(11)),
+                part: 1,
+                before: Duration::from_millis(10),
+                after: Duration::from_millis(12),
+            }]
+        );
+        assert!((deltas[0].change_percent() - 20.0).abs() < 1e-9);
+        assert!(deltas[0].is_regression(10.0));
+        assert!(!deltas[0].is_regression(25.0));
+    }
+
+    #[test]
+    fn skips_parts_without_baseline() {
+        assert!(compare(&get_mock_timings(11, 10), &get_mock_timings(12, 10)).is_empty());
+    }
+}
@@ -1,44 +1,105 @@
 use std::{env, fs};
 
-pub mod aoc_cli;
+pub mod alloc;
+pub mod aoc_client;
 pub mod commands;
+pub mod profiler;
+pub mod registry;
+pub mod report;
 pub mod runner;
 
+pub use context::*;
 pub use day::*;
+pub use error::*;
+pub use puzzle::*;
+pub use year::*;
 
+mod answers;
+mod context;
 mod day;
+mod error;
+mod examples;
+mod history;
+mod puzzle;
 mod readme_benchmarks;
 mod run_multi;
+mod submissions;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
+pub const ANSI_YELLOW: &str = "\x1b[33m";
 
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, puzzle: Puzzle) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd.join(puzzle.data_path(folder, "txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, puzzle: Puzzle, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
+        .join(puzzle.data_dir(folder))
+        .join(format!("{}-{part}.txt", puzzle.day));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Helper function that reads the example input of a part.
+/// Uses the part-specific file (e.g. `01-2.txt`) if present, and falls back to the shared file (e.g. `01.txt`).
+#[must_use]
+pub fn read_example(puzzle: Puzzle, part: u8) -> String {
+    let cwd = env::current_dir().unwrap();
+    let part_path = cwd
+        .join(puzzle.data_dir("examples"))
+        .join(format!("{}-{part}.txt", puzzle.day));
+
+    if part_path.exists() {
+        read_file_part("examples", puzzle, part)
+    } else {
+        read_file("examples", puzzle)
+    }
+}
+
+/// Helper function that reads an input file together with its [`Context`].
+/// The front matter header of the file is removed from the returned input.
+#[must_use]
+pub fn read_file_with_context(folder: &str, puzzle: Puzzle) -> (String, Context) {
+    let cwd = env::current_dir().unwrap();
+    let filepath = cwd.join(puzzle.data_path(folder, "txt"));
+    Context::read(&filepath, folder == "examples").expect("could not open input file")
+}
+
+/// Helper function that reads the example input of a part together with its [`Context`].
+/// Uses the part-specific file (e.g. `01-2.txt`) if present, and falls back to the shared file (e.g. `01.txt`).
+#[must_use]
+pub fn read_example_with_context(puzzle: Puzzle, part: u8) -> (String, Context) {
+    let cwd = env::current_dir().unwrap();
+    let part_path = cwd
+        .join(puzzle.data_dir("examples"))
+        .join(format!("{}-{part}.txt", puzzle.day));
+
+    if part_path.exists() {
+        Context::read(&part_path, true).expect("could not open input file")
+    } else {
+        read_file_with_context("examples", puzzle)
+    }
+}
+
+/// Creates the constants `DAY`, `YEAR` and `PUZZLE` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// The year is read from the file name of the solution (e.g. `2024-08.rs`), falling back to `AOC_YEAR`.
+/// Part two is not run for the last day of an event, which only has a single part.
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
@@ -55,14 +116,49 @@ macro_rules! solution {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
+        /// The year of the current day.
+        const YEAR: $crate::template::Year =
+            $crate::template::__year_from_path(file!(), option_env!("AOC_YEAR"));
+
+        /// The current puzzle, used to locate its files.
+        const PUZZLE: $crate::template::Puzzle = $crate::template::Puzzle::new(YEAR, DAY);
+
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
-        fn main() {
+        // NOTE: the in-process registry installs the counting allocator in the main binary instead.
+        #[cfg(all(feature = "count-allocations", not(feature = "registry")))]
+        #[global_allocator]
+        static ALLOC: $crate::template::alloc::CountingAlloc = $crate::template::alloc::CountingAlloc;
+
+        /// Runs all parts of the current day. Called by `main` and by the in-process solution registry.
+        pub fn __run_day(
+            options: &$crate::template::runner::RunOptions,
+        ) -> Vec<$crate::template::report::PartReport> {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let input = DayInput::new(PUZZLE, options.example);
+            let mut reports = vec![];
+            $(
+                if $part <= PUZZLE.part_count() {
+                    if has_timed_out(&reports) {
+                        println!("Part {}: skipped, a timed-out part is still running.", $part);
+                    } else {
+                        reports.push(run_part($func, &input.part($part), PUZZLE, $part, options));
+                    }
+                }
+            )*
+            reports
+        }
+
+        #[allow(dead_code)]
+        fn main() {
+            let args: Vec<String> = std::env::args().collect();
+            let options = $crate::template::runner::RunOptions::from_args(&args);
+            if let Some(timeout) = options.day_timeout {
+                $crate::template::runner::spawn_watchdog(timeout);
+            }
+            __run_day(&options);
         }
     };
 }
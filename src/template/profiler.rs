@@ -0,0 +1,591 @@
+/// Samples the call stacks of a running solution part and renders them as a flamegraph.
+/// Sampling requires the `profile` feature and linux on x86_64 or aarch64: a `SIGPROF` timer interrupts the process
+/// about a thousand times per second of CPU time and the signal handler records the current call stack by walking
+/// frame pointers. Symbols are only resolved after the timer has been stopped.
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::template::Puzzle;
+
+/// Name of the cargo feature that enables the sampler.
+pub const FEATURE: &str = "profile";
+
+/// Parts are executed repeatedly for at least this long, so fast parts collect enough samples.
+const PROFILE_TIME: Duration = Duration::from_secs(1);
+
+/// Call stacks, ordered from the root to the leaf frame, with the number of times they were sampled.
+pub type Stacks = BTreeMap<Vec<String>, usize>;
+
+/// Where the profile of a part is written to.
+pub struct ProfilePaths {
+    pub folded: PathBuf,
+    pub svg: PathBuf,
+}
+
+impl ProfilePaths {
+    /// Paths for a part of a puzzle, e.g. `data/2024/profiles/06-part1.svg`.
+    #[must_use]
+    pub fn new(puzzle: Puzzle, part: u8) -> Self {
+        let dir = puzzle.data_dir("profiles");
+        let name = format!("{}-part{part}", puzzle.day);
+        Self {
+            folded: dir.join(format!("{name}.folded")),
+            svg: dir.join(format!("{name}.svg")),
+        }
+    }
+}
+
+/// Runs `func` repeatedly while sampling its call stacks and writes a folded stacks file and a flamegraph.
+/// Returns the number of collected samples.
+#[inline(never)]
+pub fn profile_part<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    title: &str,
+    paths: &ProfilePaths,
+) -> Result<usize, String> {
+    let sampler = sampler::Sampler::start()?;
+
+    // NOTE: iterations run in doubling batches, so checking the time does not show up in profiles of fast parts.
+    let timer = Instant::now();
+    let mut batch: u64 = 1;
+    while timer.elapsed() < PROFILE_TIME {
+        for _ in 0..batch {
+            std::hint::black_box(func(std::hint::black_box(input)));
+        }
+        batch = batch.saturating_mul(2);
+    }
+
+    let stacks = sampler.finish();
+    let samples = stacks.values().sum();
+
+    if let Some(dir) = paths.svg.parent() {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    fs::write(&paths.folded, fold(&stacks)).map_err(|e| e.to_string())?;
+    fs::write(&paths.svg, flamegraph(&stacks, title)).map_err(|e| e.to_string())?;
+
+    Ok(samples)
+}
+
+/// Drops the frames of the runner, so that stacks start at the solution part.
+#[cfg_attr(
+    not(all(
+        feature = "profile",
+        target_os = "linux",
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    )),
+    allow(dead_code)
+)]
+fn trim_frames(frames: Vec<String>) -> Vec<String> {
+    let start = frames
+        .iter()
+        .rposition(|name| name.contains("profiler::profile_part"))
+        .map_or(0, |i| i + 1);
+
+    frames.into_iter().skip(start).collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Serializes stacks to the folded format of `flamegraph.pl` / `inferno`, one `root;child;leaf count` line per stack.
+#[must_use]
+pub fn fold(stacks: &Stacks) -> String {
+    let mut out = String::new();
+    for (frames, count) in stacks {
+        let _ = writeln!(out, "{} {count}", frames.join(";"));
+    }
+    out
+}
+
+#[derive(Default)]
+struct Node {
+    value: usize,
+    children: BTreeMap<String, Node>,
+}
+
+const SVG_WIDTH: f64 = 1200.0;
+const SVG_PADDING: f64 = 10.0;
+const FRAME_HEIGHT: f64 = 16.0;
+const HEADER_HEIGHT: f64 = 30.0;
+const CHAR_WIDTH: f64 = 7.0;
+
+/// Renders stacks as a self-contained SVG flamegraph. Hovering a frame shows its name and share of samples.
+#[must_use]
+pub fn flamegraph(stacks: &Stacks, title: &str) -> String {
+    let mut root = Node::default();
+    for (frames, count) in stacks {
+        root.value += count;
+        let mut node = &mut root;
+        for frame in frames {
+            node = node.children.entry(frame.clone()).or_default();
+            node.value += count;
+        }
+    }
+
+    let depth = stacks.keys().map(Vec::len).max().unwrap_or(0) + 1;
+    let height = HEADER_HEIGHT + depth as f64 * FRAME_HEIGHT + 2.0 * SVG_PADDING;
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{SVG_WIDTH}" height="{height}" viewBox="0 0 {SVG_WIDTH} {height}" font-family="monospace" font-size="12">"#
+    );
+    let _ = writeln!(
+        svg,
+        r##"<rect width="100%" height="100%" fill="#f8f8f8"/><text x="{}" y="{}" text-anchor="middle" font-size="16">{}</text>"##,
+        SVG_WIDTH / 2.0,
+        SVG_PADDING + 14.0,
+        escape(title)
+    );
+
+    if root.value > 0 {
+        let scale = (SVG_WIDTH - 2.0 * SVG_PADDING) / root.value as f64;
+        render_node(
+            &mut svg,
+            "all",
+            &root,
+            root.value,
+            0,
+            SVG_PADDING,
+            scale,
+            height,
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_node(
+    svg: &mut String,
+    name: &str,
+    node: &Node,
+    total: usize,
+    depth: usize,
+    x: f64,
+    scale: f64,
+    height: f64,
+) {
+    let width = node.value as f64 * scale;
+    if width < 0.1 {
+        return;
+    }
+
+    let y = height - SVG_PADDING - (depth + 1) as f64 * FRAME_HEIGHT;
+    let percent = node.value as f64 / total as f64 * 100.0;
+
+    let _ = write!(
+        svg,
+        r#"<g><title>{} ({} samples, {percent:.2}%)</title><rect x="{x:.2}" y="{y:.2}" width="{width:.2}" height="{}" fill="{}" rx="2"/>"#,
+        escape(name),
+        node.value,
+        FRAME_HEIGHT - 1.0,
+        color(name)
+    );
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let max_chars = ((width - 6.0) / CHAR_WIDTH).max(0.0) as usize;
+    if max_chars >= 3 {
+        let label: String = if name.chars().count() > max_chars {
+            name.chars()
+                .take(max_chars - 2)
+                .chain("..".chars())
+                .collect()
+        } else {
+            name.to_string()
+        };
+        let _ = write!(
+            svg,
+            r#"<text x="{:.2}" y="{:.2}">{}</text>"#,
+            x + 3.0,
+            y + FRAME_HEIGHT - 4.0,
+            escape(&label)
+        );
+    }
+    svg.push_str("</g>\n");
+
+    let mut child_x = x;
+    for (child_name, child) in &node.children {
+        render_node(
+            svg,
+            child_name,
+            child,
+            total,
+            depth + 1,
+            child_x,
+            scale,
+            height,
+        );
+        child_x += child.value as f64 * scale;
+    }
+}
+
+/// Picks a stable color from the warm palette of `flamegraph.pl` for a frame name.
+fn color(name: &str) -> String {
+    let hash = name.bytes().fold(2_166_136_261_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(16_777_619)
+    });
+
+    let r = 205 + hash % 50;
+    let g = (hash >> 8) % 230;
+    let b = (hash >> 16) % 55;
+    format!("rgb({r},{g},{b})")
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(
+    feature = "profile",
+    target_os = "linux",
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+mod sampler {
+    use std::collections::HashMap;
+    use std::ffi::c_void;
+    use std::mem;
+    use std::ptr;
+    use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+    use std::sync::OnceLock;
+
+    use super::{trim_frames, Stacks};
+
+    /// Sampling interval, slightly off a round number to avoid sampling in lockstep with periodic work.
+    const INTERVAL_MICROS: libc::suseconds_t = 1_009;
+    const MAX_DEPTH: usize = 128;
+    const MAX_SAMPLES: usize = 20_000;
+    /// Each slot holds the depth of a sample followed by its instruction pointers.
+    const SLOT_SIZE: usize = MAX_DEPTH + 1;
+    /// Depth of a slot that no handler has finished writing.
+    const INCOMPLETE: usize = usize::MAX;
+    /// A frame record holds the frame pointer of the caller followed by the return address.
+    const FRAME_RECORD_SIZE: usize = 2 * mem::size_of::<usize>();
+
+    static BUFFER: AtomicPtr<usize> = AtomicPtr::new(ptr::null_mut());
+    static NEXT_SLOT: AtomicUsize = AtomicUsize::new(0);
+    /// Number of handlers that may currently be writing to `BUFFER`.
+    static IN_FLIGHT: AtomicUsize = AtomicUsize::new(0);
+    /// Bounds of the stack of the profiled thread, frame pointers are only followed within it.
+    static STACK_LOW: AtomicUsize = AtomicUsize::new(0);
+    static STACK_HIGH: AtomicUsize = AtomicUsize::new(0);
+    static INSTALL_HANDLER: OnceLock<Result<(), String>> = OnceLock::new();
+
+    /// Records the instruction pointers of the interrupted call stack into the next free slot.
+    /// Only touches preallocated memory and atomics, the handler must not allocate, lock or unwind.
+    extern "C" fn handle_signal(_: libc::c_int, _: *mut libc::siginfo_t, context: *mut c_void) {
+        // NOTE: registering before loading the buffer lets `finish` wait for handlers that still hold it.
+        IN_FLIGHT.fetch_add(1, Ordering::SeqCst);
+        // SAFETY: the kernel passes the context of the interrupted code to `SA_SIGINFO` handlers.
+        record_sample(unsafe { &*context.cast::<libc::ucontext_t>() });
+        IN_FLIGHT.fetch_sub(1, Ordering::Release);
+    }
+
+    /// Instruction pointer, frame pointer and stack pointer of the interrupted code.
+    #[cfg(target_arch = "x86_64")]
+    fn registers(context: &libc::ucontext_t) -> (usize, usize, usize) {
+        let registers = &context.uc_mcontext.gregs;
+        (
+            registers[libc::REG_RIP as usize] as usize,
+            registers[libc::REG_RBP as usize] as usize,
+            registers[libc::REG_RSP as usize] as usize,
+        )
+    }
+
+    /// Instruction pointer, frame pointer and stack pointer of the interrupted code.
+    #[cfg(target_arch = "aarch64")]
+    fn registers(context: &libc::ucontext_t) -> (usize, usize, usize) {
+        let registers = &context.uc_mcontext;
+        (
+            registers.pc as usize,
+            registers.regs[29] as usize,
+            registers.sp as usize,
+        )
+    }
+
+    fn record_sample(context: &libc::ucontext_t) {
+        let buffer = BUFFER.load(Ordering::SeqCst);
+        if buffer.is_null() {
+            return;
+        }
+
+        let slot = NEXT_SLOT.fetch_add(1, Ordering::Relaxed);
+        if slot >= MAX_SAMPLES {
+            return;
+        }
+
+        let (ip, mut fp, sp) = registers(context);
+        let low = STACK_LOW.load(Ordering::Relaxed);
+        let high = STACK_HIGH.load(Ordering::Relaxed);
+
+        // SAFETY: the slot is claimed by this handler only and lies within the buffer of `MAX_SAMPLES` slots.
+        // Frame records are only read between the stack pointer and the top of the profiled stack, which is mapped.
+        unsafe {
+            let base = buffer.add(slot * SLOT_SIZE);
+            *base.add(1) = ip;
+            let mut depth = 1;
+
+            // NOTE: a stack pointer outside of the profiled stack belongs to another thread, only its leaf is recorded.
+            // Records must move strictly upwards, which ends the walk at a frame pointer that holds garbage.
+            if (low..high).contains(&sp) {
+                let mut lower = sp;
+                while depth < MAX_DEPTH
+                    && fp >= lower
+                    && fp % mem::align_of::<usize>() == 0
+                    && fp <= high - FRAME_RECORD_SIZE
+                {
+                    let record = fp as *const usize;
+                    let return_address = *record.add(1);
+                    if return_address == 0 {
+                        break;
+                    }
+
+                    *base.add(1 + depth) = return_address;
+                    depth += 1;
+                    lower = fp + FRAME_RECORD_SIZE;
+                    fp = *record;
+                }
+            }
+
+            *base = depth;
+        }
+    }
+
+    fn install_handler() -> Result<(), String> {
+        // SAFETY: the handler only writes to preallocated memory and is valid for the lifetime of the process.
+        unsafe {
+            let mut action: libc::sigaction = mem::zeroed();
+            action.sa_sigaction = handle_signal
+                as extern "C" fn(libc::c_int, *mut libc::siginfo_t, *mut c_void)
+                as usize;
+            action.sa_flags = libc::SA_RESTART | libc::SA_SIGINFO;
+            libc::sigemptyset(&mut action.sa_mask);
+            if libc::sigaction(libc::SIGPROF, &action, ptr::null_mut()) != 0 {
+                return Err(format!(
+                    "could not install signal handler: {}",
+                    std::io::Error::last_os_error()
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Bounds of the stack of the current thread.
+    fn current_stack() -> Result<(usize, usize), String> {
+        // SAFETY: `attr` is initialized by `pthread_getattr_np` before it's read and destroyed afterwards.
+        unsafe {
+            let mut attr: libc::pthread_attr_t = mem::zeroed();
+            if libc::pthread_getattr_np(libc::pthread_self(), &mut attr) != 0 {
+                return Err("could not determine the stack of the current thread.".into());
+            }
+
+            let mut address = ptr::null_mut();
+            let mut size = 0;
+            let result = libc::pthread_attr_getstack(&attr, &mut address, &mut size);
+            libc::pthread_attr_destroy(&mut attr);
+
+            match result {
+                0 => Ok((address as usize, address as usize + size)),
+                _ => Err("could not determine the stack of the current thread.".into()),
+            }
+        }
+    }
+
+    fn set_timer(interval: libc::suseconds_t) -> Result<(), String> {
+        let value = libc::timeval {
+            tv_sec: 0,
+            tv_usec: interval,
+        };
+        let timer = libc::itimerval {
+            it_interval: value,
+            it_value: value,
+        };
+
+        // SAFETY: `timer` is a valid `itimerval`, the previous value is not requested.
+        match unsafe { libc::setitimer(libc::ITIMER_PROF, &timer, ptr::null_mut()) } {
+            0 => Ok(()),
+            _ => Err(format!(
+                "could not set profiling timer: {}",
+                std::io::Error::last_os_error()
+            )),
+        }
+    }
+
+    pub struct Sampler {
+        buffer: Box<[usize]>,
+    }
+
+    impl Sampler {
+        /// Starts sampling the call stacks of the current thread.
+        pub fn start() -> Result<Self, String> {
+            // NOTE: the handler stays installed, a `SIGPROF` that arrives after stopping would otherwise terminate the process.
+            INSTALL_HANDLER.get_or_init(install_handler).clone()?;
+
+            let (low, high) = current_stack()?;
+            STACK_LOW.store(low, Ordering::Relaxed);
+            STACK_HIGH.store(high, Ordering::Relaxed);
+
+            let mut buffer = vec![0; MAX_SAMPLES * SLOT_SIZE].into_boxed_slice();
+            for slot in buffer.chunks_mut(SLOT_SIZE) {
+                slot[0] = INCOMPLETE;
+            }
+            NEXT_SLOT.store(0, Ordering::Relaxed);
+            BUFFER.store(buffer.as_mut_ptr(), Ordering::SeqCst);
+
+            set_timer(INTERVAL_MICROS)?;
+            Ok(Self { buffer })
+        }
+
+        /// Stops sampling and resolves the recorded instruction pointers to function names.
+        pub fn finish(self) -> Stacks {
+            let _ = set_timer(0);
+            BUFFER.store(ptr::null_mut(), Ordering::SeqCst);
+
+            // handlers on other threads may have loaded the buffer before it was cleared.
+            while IN_FLIGHT.load(Ordering::SeqCst) != 0 {
+                std::hint::spin_loop();
+            }
+
+            let samples = NEXT_SLOT.load(Ordering::Relaxed).min(MAX_SAMPLES);
+            let mut names: HashMap<(usize, bool), Vec<String>> = HashMap::new();
+            let mut stacks = Stacks::new();
+
+            for slot in self.buffer.chunks(SLOT_SIZE).take(samples) {
+                let depth = slot[0];
+                if depth > MAX_DEPTH {
+                    continue;
+                }
+
+                let mut frames: Vec<String> = vec![];
+
+                // walk from the root to the leaf, expanding inlined functions.
+                for (i, ip) in slot[1..=depth].iter().enumerate().rev() {
+                    let is_leaf = i == 0;
+                    let resolved = names
+                        .entry((*ip, is_leaf))
+                        .or_insert_with(|| resolve(*ip, is_leaf));
+                    frames.extend(resolved.iter().rev().cloned());
+                }
+
+                let frames = trim_frames(frames);
+                if !frames.is_empty() {
+                    *stacks.entry(frames).or_default() += 1;
+                }
+            }
+
+            stacks
+        }
+    }
+
+    /// Resolves an instruction pointer to function names, innermost inlined function first.
+    fn resolve(ip: usize, is_leaf: bool) -> Vec<String> {
+        // NOTE: return addresses point after the call instruction, which may belong to the next line or function.
+        let address = if is_leaf { ip } else { ip.saturating_sub(1) };
+
+        let mut names = vec![];
+        backtrace::resolve(address as *mut c_void, |symbol| {
+            if let Some(name) = symbol.name() {
+                names.push(format!("{name:#}"));
+            }
+        });
+
+        if names.is_empty() {
+            names.push(format!("{ip:#x}"));
+        }
+        names
+    }
+}
+
+#[cfg(not(all(
+    feature = "profile",
+    target_os = "linux",
+    any(target_arch = "x86_64", target_arch = "aarch64")
+)))]
+mod sampler {
+    use super::Stacks;
+
+    pub struct Sampler;
+
+    impl Sampler {
+        pub fn start() -> Result<Self, String> {
+            if cfg!(all(
+                target_os = "linux",
+                any(target_arch = "x86_64", target_arch = "aarch64")
+            )) {
+                Err(format!("rebuild with the `{}` feature.", super::FEATURE))
+            } else {
+                Err("profiling is only supported on linux on x86_64 and aarch64.".into())
+            }
+        }
+
+        pub fn finish(self) -> Stacks {
+            Stacks::new()
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{flamegraph, fold, trim_frames, Stacks};
+
+    fn frames(names: &[&str]) -> Vec<String> {
+        names.iter().map(|x| x.to_string()).collect()
+    }
+
+    fn get_mock_stacks() -> Stacks {
+        let mut stacks = Stacks::new();
+        stacks.insert(frames(&["main", "part_one", "parse"]), 3);
+        stacks.insert(frames(&["main", "part_one"]), 1);
+        stacks.insert(frames(&["main", "Vec<T>::push"]), 2);
+        stacks
+    }
+
+    #[test]
+    fn folds_stacks() {
+        assert_eq!(
+            fold(&get_mock_stacks()),
+            "main;Vec<T>::push 2\nmain;part_one 1\nmain;part_one;parse 3\n"
+        );
+    }
+
+    #[test]
+    fn renders_flamegraphs() {
+        let svg = flamegraph(&get_mock_stacks(), "2024 day 06 part 1");
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(svg.contains("<title>all (6 samples, 100.00%)</title>"));
+        assert!(svg.contains("<title>part_one (4 samples, 66.67%)</title>"));
+        assert!(svg.contains("Vec&lt;T&gt;::push"));
+        assert_eq!(svg.matches("<rect x=").count(), 5);
+    }
+
+    #[test]
+    fn trims_runner_frames() {
+        let stacks = frames(&[
+            "std::rt::lang_start",
+            "advent_of_code::template::profiler::profile_part",
+            "advent_of_code::template::profiler::profile_part::{{closure}}",
+            "day_06::part_one",
+            "day_06::walk",
+        ]);
+        assert_eq!(
+            trim_frames(stacks),
+            frames(&["day_06::part_one", "day_06::walk"])
+        );
+
+        let stacks = frames(&["day_06::part_one", "0x7f568d34a050"]);
+        assert_eq!(trim_frames(stacks.clone()), stacks);
+    }
+}
@@ -0,0 +1,120 @@
+use std::fmt::Display;
+use std::path::PathBuf;
+
+#[cfg(feature = "today")]
+use chrono::{Datelike, FixedOffset, Utc};
+
+use crate::template::{Day, Year};
+
+#[cfg(feature = "today")]
+const SERVER_UTC_OFFSET: i32 = -5;
+
+/// Identifies a single puzzle by the year of its event and its day.
+///
+/// Files of a puzzle are namespaced by year, e.g. `src/bin/2024-08.rs` and `data/2024/inputs/08.txt`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Puzzle {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl Puzzle {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Number of parts of this puzzle. The puzzle of the last day of an event only has one part.
+    pub fn part_count(&self) -> u8 {
+        if self.day == self.year.last_day() {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Name of the solution binary, e.g. `2024-08`.
+    #[must_use]
+    pub fn bin_name(&self) -> String {
+        format!("{}-{}", self.year, self.day)
+    }
+
+    /// Path of the solution module, e.g. `./src/bin/2024-08.rs`.
+    #[must_use]
+    pub fn bin_path(&self) -> String {
+        format!("./src/bin/{}.rs", self.bin_name())
+    }
+
+    /// Directory of a kind of data for the year of this puzzle, e.g. `data/2024/inputs`.
+    #[must_use]
+    pub fn data_dir(&self, folder: &str) -> PathBuf {
+        PathBuf::from("data")
+            .join(self.year.to_string())
+            .join(folder)
+    }
+
+    /// Path of a data file of this puzzle, e.g. `data/2024/inputs/08.txt` for `("inputs", "txt")`.
+    #[must_use]
+    pub fn data_path(&self, folder: &str, extension: &str) -> PathBuf {
+        self.data_dir(folder)
+            .join(format!("{}.{extension}", self.day))
+    }
+}
+
+#[cfg(feature = "today")]
+impl Puzzle {
+    /// Returns the puzzle of the current day if it's between the 1st of december and the last day of the event, `None` otherwise.
+    pub fn today() -> Option<Self> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let today = Utc::now().with_timezone(&offset);
+        let year = Year::new(u16::try_from(today.year()).ok()?)?;
+        if today.month() == 12 && today.day() <= u32::from(year.last_day().into_inner()) {
+            Some(Self::new(year, Day::new(u8::try_from(today.day()).ok()?)?))
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} day {}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Puzzle;
+    use crate::{day, year};
+    use std::path::PathBuf;
+
+    #[test]
+    fn namespaces_paths() {
+        let puzzle = Puzzle::new(year!(2023), day!(8));
+        assert_eq!(puzzle.bin_name(), "2023-08");
+        assert_eq!(puzzle.to_string(), "2023 day 08");
+        assert_eq!(puzzle.bin_path(), "./src/bin/2023-08.rs");
+        assert_eq!(
+            puzzle.data_path("inputs", "txt"),
+            PathBuf::from("data/2023/inputs/08.txt")
+        );
+    }
+
+    #[test]
+    fn counts_parts() {
+        assert_eq!(Puzzle::new(year!(2024), day!(24)).part_count(), 2);
+        assert_eq!(Puzzle::new(year!(2024), day!(25)).part_count(), 1);
+        assert_eq!(Puzzle::new(year!(2025), day!(12)).part_count(), 1);
+    }
+
+    #[test]
+    fn orders_by_year_first() {
+        let mut puzzles = vec![
+            Puzzle::new(year!(2024), day!(1)),
+            Puzzle::new(year!(2023), day!(25)),
+        ];
+        puzzles.sort();
+        assert_eq!(puzzles[0].year, year!(2023));
+    }
+}
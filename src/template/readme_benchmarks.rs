@@ -3,7 +3,6 @@
 use std::{fs, io};
 
 use crate::template::timings::Timings;
-use crate::template::Day;
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -25,11 +24,6 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     let matches: Vec<_> = readme.match_indices(MARKER).collect();
 
@@ -55,23 +49,62 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
 fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    // NOTE: heap columns are only shown once timings were recorded with the `count-allocations` feature.
+    let has_alloc = timings
+        .data
+        .iter()
+        .any(|timing| timing.part_1_alloc.is_some() || timing.part_2_alloc.is_some());
+
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new()];
+
+    if has_alloc {
+        lines.push("| Day | Part 1 | Part 2 | Part 1 Heap | Part 2 Heap |".into());
+        lines.push("| :---: | :---: | :---:  | :---: | :---: |".into());
+    } else {
+        lines.push("| Day | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---:  |".into());
+    }
+
+    // NOTE: only label days with their year once solutions of several years are benchmarked.
+    let has_several_years = timings
+        .data
+        .iter()
+        .any(|timing| timing.puzzle.year != timings.data[0].puzzle.year);
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
+        let label = if has_several_years {
+            format!(
+                "{} Day {}",
+                timing.puzzle.year,
+                timing.puzzle.day.into_inner()
+            )
+        } else {
+            format!("Day {}", timing.puzzle.day.into_inner())
+        };
+
+        // NOTE: the last day of an event has no second part, which should not read as unsolved.
+        let part_2 = match timing.part_2 {
+            Some(part_2) => format!("`{part_2}`"),
+            None if timing.puzzle.part_count() < 2 => "n/a".into(),
+            None => "`-`".into(),
+        };
+
+        let mut line = format!(
+            "| [{label}]({}) | `{}` | {part_2} |",
+            timing.puzzle.bin_path(),
             timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+        );
+
+        if has_alloc {
+            for alloc in [timing.part_1_alloc, timing.part_2_alloc] {
+                match alloc {
+                    Some(alloc) => line.push_str(&format!(" `{}` |", alloc.summary())),
+                    None => line.push_str(" - |"),
+                }
+            }
+        }
+
+        lines.push(line);
     }
 
     lines.push(String::new());
@@ -100,27 +133,48 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{
+        day, template::alloc::AllocStats, template::timings::Timing, template::timings::Timings,
+        template::Puzzle, year,
+    };
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
+                    puzzle: Puzzle::new(year!(2024), day!(1)),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
                     total_nanos: 3e+10,
                 },
                 Timing {
-                    day: day!(2),
+                    puzzle: Puzzle::new(year!(2024), day!(2)),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
                     total_nanos: 7e+10,
                 },
                 Timing {
-                    day: day!(4),
+                    puzzle: Puzzle::new(year!(2024), day!(4)),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
                     total_nanos: 9e+10,
                 },
             ],
@@ -169,9 +223,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2024-01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2024-02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2024-04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +234,46 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn labels_days_of_several_years() {
+        let mut timings = get_mock_timings();
+        timings.data[0].puzzle = Puzzle::new(year!(2023), day!(1));
+
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert!(s.contains("| [2023 Day 1](./src/bin/2023-01.rs) | `10ms` | `20ms` |"));
+        assert!(s.contains("| [2024 Day 2](./src/bin/2024-02.rs) | `30ms` | `40ms` |"));
+    }
+
+    #[test]
+    fn marks_missing_parts_of_single_part_days() {
+        let mut timings = get_mock_timings();
+        timings.data[1].part_2 = None;
+        timings.data[2].puzzle = Puzzle::new(year!(2024), day!(25));
+        timings.data[2].part_2 = None;
+
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert!(s.contains("| [Day 2](./src/bin/2024-02.rs) | `30ms` | `-` |"));
+        assert!(s.contains("| [Day 25](./src/bin/2024-25.rs) | `40ms` | n/a |"));
+    }
+
+    #[test]
+    fn adds_heap_columns() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1_alloc = Some(AllocStats {
+            allocations: 12,
+            bytes: 3584,
+            peak: 1024,
+        });
+
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert!(s.contains("| Day | Part 1 | Part 2 | Part 1 Heap | Part 2 Heap |"));
+        assert!(s.contains(
+            "| [Day 1](./src/bin/2024-01.rs) | `10ms` | `20ms` | `12 allocs, 3.5 KiB (peak 1.0 KiB)` | - |"
+        ));
+        assert!(s.contains("| [Day 2](./src/bin/2024-02.rs) | `30ms` | `40ms` | - | - |"));
+    }
 }
@@ -0,0 +1,52 @@
+/// Support for running solutions in-process instead of spawning one `cargo run` per day.
+/// When the `registry` feature is enabled, a build script links every `src/bin/YYYY-DD.rs` into the main binary as a module.
+use std::{
+    env,
+    process::{self, Command},
+};
+
+use crate::template::{alloc, report::PartReport, runner::RunOptions, Puzzle};
+
+/// A solution that is linked into the current binary.
+#[derive(Clone, Copy, Debug)]
+pub struct Solution {
+    pub puzzle: Puzzle,
+    pub run: fn(&RunOptions) -> Vec<PartReport>,
+}
+
+/// Finds the registered solution for a given puzzle.
+#[must_use]
+pub fn find(solutions: &[Solution], puzzle: Puzzle) -> Option<&Solution> {
+    solutions.iter().find(|s| s.puzzle == puzzle)
+}
+
+/// Re-runs the current command with a binary that is built with the `registry` feature.
+/// Keeps the `count-allocations` feature if the current binary was built with it.
+pub fn relaunch_with_registry() -> ! {
+    if alloc::ENABLED {
+        relaunch_with_features(&["registry", alloc::FEATURE])
+    } else {
+        relaunch_with_features(&["registry"])
+    }
+}
+
+/// Re-runs the current command with a binary that is built with the given features.
+pub fn relaunch_with_features(features: &[&str]) -> ! {
+    let status = Command::new("cargo")
+        .args(["run", "--quiet", "--release", "--features"])
+        .arg(features.join(","))
+        .arg("--")
+        .args(env::args().skip(1))
+        .status();
+
+    match status {
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(e) => {
+            eprintln!(
+                "Failed to relaunch with the `{}` feature: {e}",
+                features.join("`, `")
+            );
+            process::exit(1);
+        }
+    }
+}
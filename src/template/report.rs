@@ -0,0 +1,296 @@
+/// Machine-readable protocol between solution binaries and the runner.
+/// When invoked with `--json`, a solution binary emits one record line per part next to its human-readable output.
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{alloc::AllocStats, runner::BenchStats};
+
+/// Prefix that marks a record line. Starts with an ASCII record separator so it can't be confused with regular output.
+pub const RECORD_PREFIX: &str = "\u{1e}aoc-report:";
+
+/// The outcome of running a single solution part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    /// The part returned an answer.
+    Solved,
+    /// The part returned `None`.
+    Unsolved,
+    /// The part returned an error.
+    Failed,
+    /// The part did not complete within its timeout.
+    TimedOut,
+    /// The part panicked.
+    Panicked,
+}
+
+impl PartStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PartStatus::Solved => "solved",
+            PartStatus::Unsolved => "unsolved",
+            PartStatus::Failed => "failed",
+            PartStatus::TimedOut => "timed_out",
+            PartStatus::Panicked => "panicked",
+        }
+    }
+}
+
+impl FromStr for PartStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(PartStatus::Solved),
+            "unsolved" => Ok(PartStatus::Unsolved),
+            "failed" => Ok(PartStatus::Failed),
+            "timed_out" => Ok(PartStatus::TimedOut),
+            "panicked" => Ok(PartStatus::Panicked),
+            _ => Err(format!("Unknown part status `{s}`.")),
+        }
+    }
+}
+
+impl Display for PartStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A structured record describing the result of a single solution part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Option<String>,
+    pub status: PartStatus,
+    pub stats: BenchStats,
+    /// Whether the answer matches the stored answer. `None` if no answer is known.
+    pub correct: Option<bool>,
+    /// Describes why a part failed.
+    pub message: Option<String>,
+    /// Heap usage of the first execution, only measured when built with the `count-allocations` feature.
+    pub alloc: Option<AllocStats>,
+}
+
+impl PartReport {
+    /// Serializes the report to a single record line.
+    pub fn to_line(&self) -> String {
+        let json = JsonValue::from(self);
+        // NOTE: tinyjson escapes control characters, so multi-line answers stay on a single line.
+        format!("{RECORD_PREFIX}{}", json.stringify().unwrap_or_default())
+    }
+
+    /// Parses a line of child output. Returns `None` if the line is not a record line.
+    pub fn parse_line(line: &str) -> Option<Result<Self, String>> {
+        let json = line.strip_prefix(RECORD_PREFIX)?;
+
+        Some(
+            JsonValue::from_str(json)
+                .map_err(|_| "Report record is not valid JSON.".to_string())
+                .and_then(|value| PartReport::try_from(&value)),
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartReport> for JsonValue {
+    fn from(value: &PartReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "answer".into(),
+            value
+                .answer
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "status".into(),
+            JsonValue::String(value.status.as_str().into()),
+        );
+        map.insert(
+            "nanos".into(),
+            JsonValue::Number(value.stats.mean.as_nanos() as f64),
+        );
+        map.insert(
+            "samples".into(),
+            JsonValue::Number(value.stats.samples as f64),
+        );
+        map.insert("stats".into(), JsonValue::from(&value.stats));
+        map.insert(
+            "correct".into(),
+            value.correct.map_or(JsonValue::Null, JsonValue::Boolean),
+        );
+        map.insert(
+            "message".into(),
+            value
+                .message
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert(
+            "alloc".into(),
+            value
+                .alloc
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartReport {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected report to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u8)
+            .ok_or("Expected report.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected report.answer to be null or string.")?
+            .cloned();
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected report.status to be a string.")?
+            .parse()?;
+
+        let stats = json
+            .get("stats")
+            .ok_or("Expected report to have key `stats`.")
+            .map(BenchStats::try_from)??;
+
+        let correct = match json.get("correct") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                *v.get::<bool>()
+                    .ok_or("Expected report.correct to be null or boolean.")?,
+            ),
+        };
+
+        let message = json.get("message").and_then(|v| v.get::<String>()).cloned();
+
+        let alloc = match json.get("alloc") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(AllocStats::try_from(v)?),
+        };
+
+        Ok(PartReport {
+            part,
+            answer,
+            status,
+            stats,
+            correct,
+            message,
+            alloc,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PartReport, PartStatus, RECORD_PREFIX};
+    use crate::template::{alloc::AllocStats, runner::BenchStats};
+    use std::time::Duration;
+
+    fn get_mock_report(answer: Option<&str>) -> PartReport {
+        PartReport {
+            part: 2,
+            answer: answer.map(String::from),
+            status: if answer.is_some() {
+                PartStatus::Solved
+            } else {
+                PartStatus::Unsolved
+            },
+            stats: BenchStats::from_samples(&[
+                Duration::from_micros(10),
+                Duration::from_micros(30),
+            ]),
+            correct: answer.map(|x| x == "42"),
+            message: None,
+            alloc: None,
+        }
+    }
+
+    #[test]
+    fn roundtrips_reports() {
+        let report = get_mock_report(Some("42"));
+        let line = report.to_line();
+        assert!(line.starts_with(RECORD_PREFIX));
+        assert_eq!(PartReport::parse_line(&line).unwrap().unwrap(), report);
+    }
+
+    #[test]
+    fn roundtrips_alloc_stats() {
+        let report = PartReport {
+            alloc: Some(AllocStats {
+                allocations: 3,
+                bytes: 2048,
+                peak: 1024,
+            }),
+            ..get_mock_report(Some("42"))
+        };
+        assert_eq!(
+            PartReport::parse_line(&report.to_line()).unwrap().unwrap(),
+            report
+        );
+    }
+
+    #[test]
+    fn handles_multiline_answers() {
+        let report = get_mock_report(Some("foo (1ms @ 10 samples)\nbar"));
+        let line = report.to_line();
+        assert_eq!(line.lines().count(), 1);
+        assert_eq!(PartReport::parse_line(&line).unwrap().unwrap(), report);
+    }
+
+    #[test]
+    fn handles_missing_answers() {
+        let report = get_mock_report(None);
+        let parsed = PartReport::parse_line(&report.to_line()).unwrap().unwrap();
+        assert_eq!(parsed.answer, None);
+        assert_eq!(parsed.status, PartStatus::Unsolved);
+    }
+
+    #[test]
+    fn handles_failed_parts() {
+        let report = PartReport {
+            status: PartStatus::Panicked,
+            message: Some("panicked: boom".into()),
+            ..get_mock_report(None)
+        };
+        assert_eq!(
+            PartReport::parse_line(&report.to_line()).unwrap().unwrap(),
+            report
+        );
+    }
+
+    #[test]
+    fn ignores_regular_output() {
+        assert!(PartReport::parse_line("Part 1: 42 (1ms @ 10 samples)").is_none());
+    }
+
+    #[test]
+    fn errors_for_malformed_records() {
+        let line = format!("{RECORD_PREFIX}{{ \"part\": 1 }}");
+        assert!(PartReport::parse_line(&line).unwrap().is_err());
+    }
+}
@@ -1,41 +1,175 @@
-use std::{collections::HashSet, io};
-
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::{
+    collections::{HashMap, HashSet},
+    io,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
 
-use super::{
-    all_days,
-    timings::{Timing, Timings},
+use crate::template::{
+    registry::{self, Solution},
+    report::{PartReport, PartStatus},
+    runner::{self, BenchConfig, RunOptions, Timeouts},
+    Puzzle, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+use super::timings::{Timing, Timings};
 
-    let mut need_space = false;
+use child_commands::OutputSink;
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+/// Collects the results of all days while they are printed.
+#[derive(Default)]
+struct RunSummary {
+    need_space: bool,
+    timings: Vec<Timing>,
+    regressions: Vec<String>,
+    failures: Vec<String>,
+}
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+impl RunSummary {
+    fn print_header(&mut self, puzzle: Puzzle) {
+        if self.need_space {
+            println!();
+        }
+        self.need_space = true;
+
+        println!("{ANSI_BOLD}{}{ANSI_RESET}", puzzle.bin_name());
+        println!("-------");
+    }
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    fn add_reports(&mut self, puzzle: Puzzle, reports: &[PartReport]) {
+        self.regressions.extend(
+            reports
+                .iter()
+                .filter(|report| report.correct == Some(false))
+                .map(|report| format!("{} Part {}", puzzle.bin_name(), report.part)),
+        );
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+        self.failures.extend(
+            reports
+                .iter()
+                .filter(|report| {
+                    matches!(
+                        report.status,
+                        PartStatus::Failed | PartStatus::TimedOut | PartStatus::Panicked
+                    )
+                })
+                .map(|report| {
+                    format!(
+                        "{} Part {}: {}",
+                        puzzle.bin_name(),
+                        report.part,
+                        report.message.as_deref().unwrap_or(report.status.as_str())
+                    )
+                }),
+        );
+
+        if reports.is_empty() {
+            println!("Not solved.");
+        } else {
+            self.timings
+                .push(child_commands::build_timing(reports, puzzle));
+        }
+    }
+}
+
+/// Runs the given puzzles and prints their output.
+/// With `jobs > 1`, up to `jobs` solution binaries run at once. Their output is buffered and printed in day order.
+/// Timed runs and solutions linked into this binary always run sequentially.
+pub fn run_multi(
+    puzzles_to_run: &HashSet<Puzzle>,
+    is_release: bool,
+    is_timed: bool,
+    bench_config: &BenchConfig,
+    timeouts: &Timeouts,
+    jobs: usize,
+    solutions: Option<&[Solution]>,
+) -> Option<Timings> {
+    let mut summary = RunSummary::default();
+
+    // NOTE: use non-duplicate, sorted puzzle values.
+    let mut puzzles: Vec<Puzzle> = puzzles_to_run.iter().copied().collect();
+    puzzles.sort_unstable();
+
+    match solutions {
+        // run solutions that are linked into this binary directly.
+        Some(solutions) => {
+            for (index, &puzzle) in puzzles.iter().enumerate() {
+                summary.print_header(puzzle);
+
+                let reports = registry::find(solutions, puzzle).map_or_else(Vec::new, |s| {
+                    (s.run)(&RunOptions {
+                        bench: is_timed.then_some(*bench_config),
+                        part_timeout: timeouts.part,
+                        ..RunOptions::default()
+                    })
+                });
+
+                summary.add_reports(puzzle, &reports);
+
+                // NOTE: a part that times out in-process can't be stopped, it would distort the results of all later days.
+                if runner::has_timed_out(&reports) {
+                    if let Some(next) = puzzles.get(index + 1) {
+                        summary.failures.push(format!(
+                            "{} and later days: skipped, a timed-out part is still running in-process.",
+                            next.bin_name()
+                        ));
+                    }
+                    break;
+                }
             }
-        });
+        }
+        // NOTE: benchmarks of concurrently running days would influence each other.
+        None if jobs > 1 && !is_timed => {
+            run_parallel(
+                &puzzles,
+                jobs,
+                is_release,
+                timeouts,
+                |puzzle, reports, output| {
+                    summary.print_header(puzzle);
+                    output.flush();
+                    summary.add_reports(puzzle, &reports);
+                },
+            );
+        }
+        None => puzzles.iter().for_each(|&puzzle| {
+            summary.print_header(puzzle);
+
+            let reports = child_commands::run_solution(
+                puzzle,
+                is_timed,
+                is_release,
+                bench_config,
+                timeouts,
+                &OutputSink::Inherit,
+            )
+            .unwrap();
+
+            summary.add_reports(puzzle, &reports);
+        }),
+    }
+
+    if !summary.regressions.is_empty() {
+        println!(
+            "\n{ANSI_BOLD}Regressions:{ANSI_RESET} {}",
+            summary.regressions.join(", ")
+        );
+    }
+
+    if !summary.failures.is_empty() {
+        println!("\n{ANSI_BOLD}Failures:{ANSI_RESET}");
+        for failure in &summary.failures {
+            println!("  {failure}");
+        }
+    }
 
     if is_timed {
-        let timings = Timings { data: timings };
+        let timings = Timings {
+            data: summary.timings,
+        };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
@@ -46,6 +180,62 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
     }
 }
 
+/// Runs the solution binaries of `puzzles` on `jobs` worker threads with buffered output.
+/// `on_day` is called in the order of `puzzles`, as soon as a day and all days before it have finished.
+fn run_parallel(
+    puzzles: &[Puzzle],
+    jobs: usize,
+    is_release: bool,
+    timeouts: &Timeouts,
+    mut on_day: impl FnMut(Puzzle, Vec<PartReport>, &OutputSink),
+) {
+    let next_day = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(puzzles.len()) {
+            let sender = sender.clone();
+            let next_day = &next_day;
+
+            scope.spawn(move || loop {
+                let index = next_day.fetch_add(1, Ordering::Relaxed);
+                let Some(&puzzle) = puzzles.get(index) else {
+                    break;
+                };
+
+                let output = OutputSink::buffered();
+                let reports = child_commands::run_solution(
+                    puzzle,
+                    false,
+                    is_release,
+                    &BenchConfig::default(),
+                    timeouts,
+                    &output,
+                )
+                .unwrap();
+
+                if sender.send((index, reports, output)).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(sender);
+
+        let mut finished = HashMap::new();
+        let mut next_to_print = 0;
+
+        for (index, reports, output) in receiver {
+            finished.insert(index, (reports, output));
+
+            while let Some((reports, output)) = finished.remove(&next_to_print) {
+                on_day(puzzles[next_to_print], reports, &output);
+                next_to_print += 1;
+            }
+        }
+    });
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
@@ -59,48 +249,164 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the report records they emit.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::Error;
+    use crate::template::{
+        alloc,
+        report::PartReport,
+        report::PartStatus,
+        runner::{BenchConfig, BenchStats, Timeouts},
+        Puzzle,
+    };
     use std::{
         io::{BufRead, BufReader},
         path::Path,
         process::{Command, Stdio},
+        sync::{
+            mpsc::{self, RecvTimeoutError},
+            Arc, Mutex,
+        },
         thread,
+        time::{Duration, Instant},
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// A line of output of a child command.
+    pub enum OutputLine {
+        Stdout(String),
+        Stderr(String),
+    }
+
+    /// Receives the human-readable output of a child command.
+    #[derive(Clone)]
+    pub enum OutputSink {
+        /// Forward output to stdout / stderr immediately.
+        Inherit,
+        /// Hold output back until [`OutputSink::flush`] is called.
+        Buffered(Arc<Mutex<Vec<OutputLine>>>),
+    }
+
+    impl OutputSink {
+        #[must_use]
+        pub fn buffered() -> Self {
+            OutputSink::Buffered(Arc::new(Mutex::new(vec![])))
+        }
+
+        fn push(&self, line: OutputLine) {
+            match self {
+                OutputSink::Inherit => match line {
+                    OutputLine::Stdout(line) => println!("{line}"),
+                    OutputLine::Stderr(line) => eprintln!("{line}"),
+                },
+                OutputSink::Buffered(lines) => lines.lock().unwrap().push(line),
+            }
+        }
+
+        fn println(&self, line: String) {
+            self.push(OutputLine::Stdout(line));
+        }
+
+        fn eprintln(&self, line: String) {
+            self.push(OutputLine::Stderr(line));
+        }
+
+        /// Prints all buffered output.
+        pub fn flush(&self) {
+            if let OutputSink::Buffered(lines) = self {
+                for line in lines.lock().unwrap().drain(..) {
+                    match line {
+                        OutputLine::Stdout(line) => println!("{line}"),
+                        OutputLine::Stderr(line) => eprintln!("{line}"),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Run the solution bin for a given puzzle
+    pub fn run_solution(
+        puzzle: Puzzle,
+        is_timed: bool,
+        is_release: bool,
+        bench_config: &BenchConfig,
+        timeouts: &Timeouts,
+        output: &OutputSink,
+    ) -> Result<Vec<PartReport>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+        if !Path::new(&puzzle.bin_path()).exists() {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let mut args: Vec<String> = vec![
+            "run".into(),
+            "--quiet".into(),
+            "--bin".into(),
+            puzzle.bin_name(),
+        ];
 
         if is_release {
-            args.push("--release");
+            args.push("--release".into());
+        }
+
+        // count allocations of the child if the runner counts them.
+        if alloc::ENABLED {
+            args.push("--features".into());
+            args.push(alloc::FEATURE.into());
         }
 
+        // request machine-readable report records from the child.
+        args.push("--".into());
+        args.push("--json".into());
+
         if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+            // mirror `--time` flag and bench config to child invocations.
+            args.push("--time".into());
+            args.extend(bench_config.to_args());
         }
 
+        if let Some(part_timeout) = timeouts.part {
+            args.push("--part-timeout".into());
+            args.push(part_timeout.as_secs_f64().to_string());
+        }
+
+        if let Some(day_timeout) = timeouts.day {
+            args.push("--day-timeout".into());
+            args.push(day_timeout.as_secs_f64().to_string());
+        }
+
+        collect_reports(&args, puzzle.part_count(), timeouts.day, output)
+    }
+
+    /// Run the solution bin for a given puzzle against its example inputs.
+    pub fn run_example(puzzle: Puzzle) -> Result<Vec<PartReport>, Error> {
+        let args: Vec<String> = vec![
+            "run".into(),
+            "--quiet".into(),
+            "--bin".into(),
+            puzzle.bin_name(),
+            "--".into(),
+            "--json".into(),
+            "--example".into(),
+        ];
+
+        collect_reports(&args, puzzle.part_count(), None, &OutputSink::Inherit)
+    }
+
+    /// Runs a child command and collects its reports.
+    /// Parts up to `part_count` are reported as failed when the child dies or is killed before reporting on its own.
+    fn collect_reports(
+        args: &[String],
+        part_count: u8,
+        timeout: Option<Duration>,
+        output: &OutputSink,
+    ) -> Result<Vec<PartReport>, Error> {
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward output to stdout/stderr while grabbing report records.
+        // the child is killed if it does not finish before the timeout.
 
         let mut cmd = Command::new("cargo")
-            .args(&args)
+            .args(args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()?;
@@ -108,150 +414,218 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        let mut reports = vec![];
 
+        let stderr_output = output.clone();
         let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+            stderr.lines().map_while(Result::ok).for_each(|line| {
+                stderr_output.eprintln(line);
             });
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for line in stdout.lines().map_while(Result::ok) {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        let mut timed_out = false;
+
+        loop {
+            let received = match deadline {
+                Some(deadline) => {
+                    receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                }
+                None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+            };
+
+            match received {
+                Ok(line) => match PartReport::parse_line(&line) {
+                    Some(Ok(report)) => reports.push(report),
+                    Some(Err(e)) => output.eprintln(format!("Could not parse report record: {e}")),
+                    None => output.println(line),
+                },
+                Err(RecvTimeoutError::Timeout) => {
+                    timed_out = true;
+                    cmd.kill()?;
+                    break;
+                }
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
         }
 
+        let status = cmd.wait()?;
         thread.join().unwrap();
-        cmd.wait()?;
 
-        Ok(output)
+        // report parts that never finished because the child was killed or crashed.
+        let message = match (timed_out, timeout) {
+            (true, Some(timeout)) => Some((
+                PartStatus::TimedOut,
+                format!("day timed out after {timeout:.1?}"),
+            )),
+            _ if !status.success() => Some((PartStatus::Panicked, format!("exited with {status}"))),
+            _ => None,
+        };
+
+        if let Some((status, message)) = message {
+            for part in 1..=part_count {
+                if !reports.iter().any(|report| report.part == part) {
+                    output.println(format!("Part {part}: ✖ {message}"));
+                    reports.push(PartReport {
+                        part,
+                        answer: None,
+                        status,
+                        stats: BenchStats::default(),
+                        correct: None,
+                        message: Some(message.clone()),
+                        alloc: None,
+                    });
+                }
+            }
+        }
+
+        Ok(reports)
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
+    pub fn build_timing(reports: &[PartReport], puzzle: Puzzle) -> super::Timing {
         let mut timings = super::Timing {
-            day,
+            puzzle,
             part_1: None,
             part_2: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            part_1_status: None,
+            part_2_status: None,
+            part_1_alloc: None,
+            part_2_alloc: None,
             total_nanos: 0_f64,
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
-
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+        for report in reports {
+            match report.part {
+                1 => timings.part_1_status = Some(report.status),
+                2 => timings.part_2_status = Some(report.status),
+                _ => {}
+            }
+        }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+        reports
+            .iter()
+            .filter(|report| report.status == PartStatus::Solved)
+            .for_each(|report| {
+                let stats = report.stats;
+                let timing_str = format!("{:.1?}", stats.mean);
+
+                match report.part {
+                    1 => {
+                        timings.part_1 = Some(timing_str);
+                        timings.part_1_stats = Some(stats);
+                        timings.part_1_alloc = report.alloc;
+                    }
+                    2 => {
+                        timings.part_2 = Some(timing_str);
+                        timings.part_2_stats = Some(stats);
+                        timings.part_2_alloc = report.alloc;
+                    }
+                    _ => return,
                 }
 
-                timings.total_nanos += nanos;
+                timings.total_nanos += stats.mean.as_nanos() as f64;
             });
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
-
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
-    }
-
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
-    }
-
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::build_timing;
+        use std::time::Duration;
 
-        use crate::day;
+        use crate::{
+            day,
+            template::{
+                report::{PartReport, PartStatus},
+                runner::BenchStats,
+                Puzzle,
+            },
+            year,
+        };
+
+        fn get_mock_report(part: u8, answer: Option<&str>, nanos: u64) -> PartReport {
+            PartReport {
+                part,
+                answer: answer.map(String::from),
+                status: if answer.is_some() {
+                    PartStatus::Solved
+                } else {
+                    PartStatus::Unsolved
+                },
+                stats: BenchStats::from_samples(&[Duration::from_nanos(nanos)]),
+                correct: None,
+                message: None,
+                alloc: None,
+            }
+        }
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
+        fn builds_timings_from_reports() {
+            let res = build_timing(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    get_mock_report(1, Some("0"), 74),
+                    get_mock_report(2, Some("10"), 74_130_000),
                 ],
-                day!(1),
+                Puzzle::new(year!(2024), day!(1)),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_eq!(res.total_nanos, 74_130_074_f64);
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
+            assert_eq!(res.part_1_stats.unwrap().mean, Duration::from_nanos(74));
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
+        fn handles_answers_with_patterns() {
+            let res = build_timing(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    get_mock_report(1, Some("@ @ @ ( ) ms (2s @ 5 samples)"), 2_000_000_000),
+                    get_mock_report(2, Some("a\nb samples)"), 100_000_000),
                 ],
-                day!(1),
+                Puzzle::new(year!(2024), day!(1)),
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.total_nanos, 2_100_000_000_f64);
+            assert_eq!(res.part_1.unwrap(), "2.0s");
+            assert_eq!(res.part_2.unwrap(), "100.0ms");
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn handles_missing_parts() {
+            let res = build_timing(
+                &[get_mock_report(1, None, 10), get_mock_report(2, None, 10)],
+                Puzzle::new(year!(2024), day!(1)),
+            );
+            assert_eq!(res.total_nanos, 0_f64);
+            assert!(res.part_1.is_none());
+            assert!(res.part_2.is_none());
+            assert!(res.part_1_stats.is_none());
+            assert_eq!(res.part_1_status, Some(PartStatus::Unsolved));
+        }
+
+        #[test]
+        fn records_failed_parts() {
+            let failed = PartReport {
+                status: PartStatus::TimedOut,
+                ..get_mock_report(2, None, 10)
+            };
+            let res = build_timing(
+                &[get_mock_report(1, Some("1"), 10), failed],
+                Puzzle::new(year!(2024), day!(1)),
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+            assert_eq!(res.part_1_status, Some(PartStatus::Solved));
+            assert_eq!(res.part_2_status, Some(PartStatus::TimedOut));
+            assert!(res.part_2.is_none());
         }
     }
 }
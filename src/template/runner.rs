@@ -1,63 +1,646 @@
 /// Encapsulates code that interacts with solution functions.
+use std::cell::{Cell, OnceCell, RefCell};
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::panic::AssertUnwindSafe;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, OnceLock};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, panic, process, thread};
 
-use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::alloc::{AllocMeasurement, AllocStats};
+use crate::template::answers::Answers;
+use crate::template::aoc_client::{Hint, SubmissionVerdict};
+use crate::template::profiler::{self, ProfilePaths};
+use crate::template::report::{PartReport, PartStatus};
+use crate::template::submissions::{self, Cooldown, Submissions, Verdict};
+use crate::template::{
+    aoc_client, read_example_with_context, read_file_with_context, Context, Puzzle, ANSI_ITALIC,
+    ANSI_RESET,
+};
+use crate::template::{ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_YELLOW};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Options that control how solution parts are run.
+/// Solution binaries parse these from their command-line, the in-process registry constructs them directly.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RunOptions {
+    /// Bench every part with this config. If `None`, parts are executed once.
+    pub bench: Option<BenchConfig>,
+    /// Emit a machine-readable report record per part.
+    pub json: bool,
+    /// Submit the result of this part.
+    pub submit: Option<u8>,
+    /// Run against the example inputs instead of the real input.
+    pub example: bool,
+    /// Abort a part if its first execution takes longer than this.
+    pub part_timeout: Option<Duration>,
+    /// Exit the process if running all parts takes longer than this.
+    pub day_timeout: Option<Duration>,
+    /// Sample the call stacks of every part and write a flamegraph.
+    pub profile: bool,
+}
+
+/// Time limits for running solutions. Limits that are `None` are not enforced.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Timeouts {
+    /// Limit for running all parts of a day, including compilation of its binary.
+    pub day: Option<Duration>,
+    /// Limit for the first execution of a single part.
+    pub part: Option<Duration>,
+}
+
+/// Reasons why a solution part did not complete.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PartFailure {
+    TimedOut(Duration),
+    Panicked(String),
+}
+
+impl PartFailure {
+    #[must_use]
+    pub fn status(&self) -> PartStatus {
+        match self {
+            PartFailure::TimedOut(_) => PartStatus::TimedOut,
+            PartFailure::Panicked(_) => PartStatus::Panicked,
+        }
+    }
+}
+
+impl Display for PartFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartFailure::TimedOut(timeout) => write!(f, "timed out after {timeout:.1?}"),
+            PartFailure::Panicked(message) => write!(f, "panicked: {message}"),
+        }
+    }
+}
+
+impl RunOptions {
+    /// Parses the `--time`, `--json`, `--example`, `--submit`, `--part-timeout`, `--day-timeout` and `--profile` flags and the bench config from `args`.
+    #[must_use]
+    pub fn from_args(args: &[String]) -> Self {
+        let bench = args
+            .iter()
+            .any(|x| x == "--time")
+            .then(|| BenchConfig::from_env_and_args(args));
+
+        let submit = args.iter().position(|x| x == "--submit").map(|i| {
+            let Some(Ok(part)) = args.get(i + 1).map(|x| x.parse::<u8>()) else {
+                eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+                process::exit(1);
+            };
+            part
+        });
+
+        let secs = |key: &str| {
+            args.iter()
+                .position(|x| x == key)
+                .and_then(|i| args.get(i + 1))
+                .and_then(|x| x.parse::<f64>().ok())
+                .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        };
+
+        Self {
+            bench,
+            json: args.iter().any(|x| x == "--json"),
+            submit,
+            example: args.iter().any(|x| x == "--example"),
+            part_timeout: secs("--part-timeout"),
+            day_timeout: secs("--day-timeout"),
+            profile: args.iter().any(|x| x == "--profile"),
+        }
+    }
+}
+
+/// Grace period before a child exits on its own after its day timeout.
+/// The runner kills the child when the timeout is reached, the watchdog only ensures that no orphaned process outlives it.
+const WATCHDOG_GRACE: Duration = Duration::from_secs(1);
+
+/// Exits the process once `timeout` (plus a grace period) has elapsed.
+pub fn spawn_watchdog(timeout: Duration) {
+    thread::spawn(move || {
+        thread::sleep(timeout + WATCHDOG_GRACE);
+        process::exit(1);
+    });
+}
+
+/// Return types of solution parts.
+/// `Option<T>` marks a part as unsolved with `None`, `Result<T, E>` marks a part as failed with an error message.
+pub trait PartResult: Send + 'static {
+    type Answer: Display;
+
+    /// Returns the answer, `Ok(None)` if the part is unsolved or the error message if it failed.
+    fn answer(&self) -> Result<Option<&Self::Answer>, String>;
+}
+
+impl<T: Display + Send + 'static> PartResult for Option<T> {
+    type Answer = T;
+
+    fn answer(&self) -> Result<Option<&T>, String> {
+        Ok(self.as_ref())
+    }
+}
+
+impl<T: Display + Send + 'static, E: Display + Send + 'static> PartResult for Result<T, E> {
+    type Answer = T;
+
+    fn answer(&self) -> Result<Option<&T>, String> {
+        self.as_ref().map(Some).map_err(ToString::to_string)
+    }
+}
+
+/// Solution part functions, either `fn(&str) -> R` or `fn(&str, &Context) -> R`.
+/// `Args` only tells the two shapes apart and is inferred from the function.
+pub trait PartFn<Args>: Copy + Send + 'static {
+    type Output: PartResult;
+
+    fn call(&self, input: &str, context: &Context) -> Self::Output;
+}
+
+impl<F, R> PartFn<fn(&str)> for F
+where
+    F: Fn(&str) -> R + Copy + Send + 'static,
+    R: PartResult,
+{
+    type Output = R;
+
+    fn call(&self, input: &str, _context: &Context) -> R {
+        self(input)
+    }
+}
+
+impl<F, R> PartFn<fn(&str, &Context)> for F
+where
+    F: Fn(&str, &Context) -> R + Copy + Send + 'static,
+    R: PartResult,
+{
+    type Output = R;
+
+    fn call(&self, input: &str, context: &Context) -> R {
+        self(input, context)
+    }
+}
+
+/// An input and its context, shared with the threads that run the parts of a day.
+pub type SharedInput = (Arc<str>, Arc<Context>);
+
+/// Reads the inputs of the parts of a day.
+/// The real input is read once and shared by all parts, examples are read per part since a part can have its own.
+pub struct DayInput {
+    puzzle: Puzzle,
+    example: bool,
+    input: OnceCell<SharedInput>,
+}
+
+impl DayInput {
+    #[must_use]
+    pub fn new(puzzle: Puzzle, example: bool) -> Self {
+        Self {
+            puzzle,
+            example,
+            input: OnceCell::new(),
+        }
+    }
+
+    /// The input of a part.
+    #[must_use]
+    pub fn part(&self, part: u8) -> SharedInput {
+        let share = |(input, context): (String, Context)| (Arc::from(input), Arc::new(context));
+
+        if self.example {
+            share(read_example_with_context(self.puzzle, part))
+        } else {
+            self.input
+                .get_or_init(|| share(read_file_with_context("inputs", self.puzzle)))
+                .clone()
+        }
+    }
+}
+
+/// Whether a part in `reports` timed out. Its thread can't be stopped and keeps running in the background,
+/// so every part measured after it competes for a core and for the allocator.
+#[must_use]
+pub fn has_timed_out(reports: &[PartReport]) -> bool {
+    reports
+        .iter()
+        .any(|report| report.status == PartStatus::TimedOut)
+}
+
+pub fn run_part<Args, F: PartFn<Args>>(
+    func: F,
+    input: &SharedInput,
+    puzzle: Puzzle,
+    part: u8,
+    options: &RunOptions,
+) -> PartReport {
     let part_str = format!("Part {part}");
+    let call = move |(input, context): (&str, &Context)| func.call(input, context);
+
+    let timed = run_timed(
+        call,
+        input,
+        options.bench.as_ref(),
+        options.part_timeout,
+        |result: &F::Output| {
+            print_result(&result.answer(), &part_str, "");
+        },
+    );
+
+    let (result, stats, alloc) = match timed {
+        Ok(timed) => timed,
+        Err(failure) => {
+            println!("{part_str}: {ANSI_BOLD}✖ {failure}{ANSI_RESET}");
+
+            let report = PartReport {
+                part,
+                answer: None,
+                status: failure.status(),
+                stats: BenchStats::default(),
+                correct: None,
+                message: Some(failure.to_string()),
+                alloc: None,
+            };
+
+            if options.json {
+                println!("{}", report.to_line());
+            }
+
+            return report;
+        }
+    };
+
+    let outcome = result.answer();
+    let answer = outcome.clone().ok().flatten().map(ToString::to_string);
+    // stored answers only apply to the real input.
+    let correct = answer
+        .as_ref()
+        .filter(|_| !options.example)
+        .and_then(|answer| Answers::read_from_file(puzzle).check(part, answer));
+
+    print_result(
+        &outcome,
+        &part_str,
+        &format!(
+            "{}{}{}",
+            format_stats(&stats),
+            format_alloc(alloc.as_ref()),
+            format_verification(correct)
+        ),
+    );
+
+    let report = PartReport {
+        part,
+        answer,
+        status: match outcome {
+            Ok(Some(_)) => PartStatus::Solved,
+            Ok(None) => PartStatus::Unsolved,
+            Err(_) => PartStatus::Failed,
+        },
+        stats,
+        correct,
+        message: outcome.as_ref().err().cloned(),
+        alloc,
+    };
+
+    if options.json {
+        println!("{}", report.to_line());
+    }
+
+    if options.profile && outcome.is_ok() {
+        profile_part(call, (&*input.0, &*input.1), puzzle, part);
+    }
+
+    if let Ok(Some(result)) = outcome {
+        if options.submit == Some(part) && !options.example {
+            submit_result(&result.to_string(), puzzle, part);
+        }
+    }
+
+    report
+}
+
+/// Summary statistics over the samples collected while running a solution part.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BenchStats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub stddev: Duration,
+    pub p95: Duration,
+    pub samples: u128,
+}
+
+impl BenchStats {
+    /// Computes statistics for a set of samples. Returns the default value if `samples` is empty.
+    #[must_use]
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let len = sorted.len();
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+        let mean = nanos.iter().sum::<f64>() / len as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / len as f64;
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+        let median = if len.is_multiple_of(2) {
+            (nanos[len / 2 - 1] + nanos[len / 2]) / 2.0
+        } else {
+            nanos[len / 2]
+        };
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+        // nearest-rank percentile.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let p95_index = ((len as f64 * 0.95).ceil() as usize).clamp(1, len) - 1;
+
+        Self {
+            mean: nanos_to_duration(mean),
+            median: nanos_to_duration(median),
+            min: sorted[0],
+            max: sorted[len - 1],
+            stddev: nanos_to_duration(variance.sqrt()),
+            p95: sorted[p95_index],
+            samples: len as u128,
+        }
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn nanos_to_duration(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+/// Controls how long and how often a solution part is benched.
+///
+/// Values are resolved from the defaults, then the `AOC_BENCH_*` environment variables, then the
+/// `--warmup`, `--bench-time`, `--min-samples` and `--max-samples` command-line flags.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    pub warmup: Duration,
+    pub bench_time: Duration,
+    pub min_samples: u128,
+    pub max_samples: u128,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: Duration::ZERO,
+            bench_time: Duration::from_secs(1),
+            min_samples: 10,
+            max_samples: 10000,
+        }
+    }
+}
+
+impl BenchConfig {
+    pub const WARMUP_ENV: &'static str = "AOC_BENCH_WARMUP_MS";
+    pub const BENCH_TIME_ENV: &'static str = "AOC_BENCH_TIME_MS";
+    pub const MIN_SAMPLES_ENV: &'static str = "AOC_BENCH_MIN_SAMPLES";
+    pub const MAX_SAMPLES_ENV: &'static str = "AOC_BENCH_MAX_SAMPLES";
+
+    /// Creates a config from the defaults, overridden by any `AOC_BENCH_*` environment variables.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let var = |key: &str| env::var(key).ok();
+        Self::default().with_overrides(
+            var(Self::WARMUP_ENV).as_deref(),
+            var(Self::BENCH_TIME_ENV).as_deref(),
+            var(Self::MIN_SAMPLES_ENV).as_deref(),
+            var(Self::MAX_SAMPLES_ENV).as_deref(),
+        )
+    }
+
+    /// Creates a config from the environment, overridden by any bench flags in `args`.
+    #[must_use]
+    pub fn from_env_and_args(args: &[String]) -> Self {
+        let flag = |name: &str| {
+            args.iter()
+                .position(|x| x == name)
+                .and_then(|i| args.get(i + 1))
+                .map(String::as_str)
+        };
+
+        Self::from_env().with_overrides(
+            flag("--warmup"),
+            flag("--bench-time"),
+            flag("--min-samples"),
+            flag("--max-samples"),
+        )
+    }
+
+    fn with_overrides(
+        mut self,
+        warmup_ms: Option<&str>,
+        bench_time_ms: Option<&str>,
+        min_samples: Option<&str>,
+        max_samples: Option<&str>,
+    ) -> Self {
+        if let Some(ms) = warmup_ms.and_then(|x| x.parse().ok()) {
+            self.warmup = Duration::from_millis(ms);
+        }
+        if let Some(ms) = bench_time_ms.and_then(|x| x.parse().ok()) {
+            self.bench_time = Duration::from_millis(ms);
+        }
+        if let Some(n) = min_samples.and_then(|x| x.parse().ok()) {
+            self.min_samples = n;
+        }
+        if let Some(n) = max_samples.and_then(|x| x.parse().ok()) {
+            self.max_samples = n;
+        }
+        self
+    }
+
+    /// Serializes the config to command-line flags, used to forward it to child invocations.
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        vec![
+            "--warmup".into(),
+            self.warmup.as_millis().to_string(),
+            "--bench-time".into(),
+            self.bench_time.as_millis().to_string(),
+            "--min-samples".into(),
+            self.min_samples.to_string(),
+            "--max-samples".into(),
+            self.max_samples.to_string(),
+        ]
+    }
+
+    /// Number of bench iterations for a function that took `base_time` to run once.
+    fn iterations(&self, base_time: &Duration) -> u128 {
+        let min = cmp::max(self.min_samples, 1);
+        let max = cmp::max(self.max_samples, min);
+        (self.bench_time.as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(min, max)
     }
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Copy, T>(
-    func: impl Fn(I) -> T,
-    input: I,
+///
+/// The bench budget, warm-up and sample bounds can be configured via [`BenchConfig`].
+///
+/// The first execution runs isolated on its own thread, so a panic or a timeout is reported as [`PartFailure`].
+/// With the `count-allocations` feature, the heap usage of the first execution is measured as well.
+fn run_timed<T>(
+    func: impl Fn((&str, &Context)) -> T + Copy + Send + 'static,
+    input: &SharedInput,
+    bench_config: Option<&BenchConfig>,
+    timeout: Option<Duration>,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = {
-        #[cfg(feature = "dhat-heap")]
-        let _profiler = dhat::Profiler::new_heap();
-
-        func(input)
-    };
-    let base_time = timer.elapsed();
+) -> Result<(T, BenchStats, Option<AllocStats>), PartFailure>
+where
+    T: Send + 'static,
+{
+    let (result, base_time, alloc) = run_isolated(
+        move |(input, context): SharedInput| func((&input, &context)),
+        input.clone(),
+        timeout,
+    )?;
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let stats = if let Some(config) = bench_config {
+        bench(func, (&*input.0, &*input.1), &base_time, config)
     } else {
-        (base_time, 1)
+        BenchStats::from_samples(&[base_time])
+    };
+
+    Ok((result, stats, alloc))
+}
+
+/// Stack size of the thread that runs a part, matches the default main thread stack size on linux.
+const PART_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+thread_local! {
+    /// Whether panics on this thread are captured by the hook of [`install_panic_hook`] instead of printed.
+    static CAPTURE_PANICS: Cell<bool> = const { Cell::new(false) };
+    /// Message of the last captured panic on this thread.
+    static CAPTURED_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static PANIC_HOOK: OnceLock<()> = OnceLock::new();
+
+/// Installs a process-wide panic hook once. It stores the message of panics on threads that capture panics
+/// and defers to the previous hook everywhere else.
+fn install_panic_hook() {
+    PANIC_HOOK.get_or_init(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CAPTURE_PANICS.with(Cell::get) {
+                previous_hook(info);
+                return;
+            }
+
+            let payload = info
+                .payload()
+                .downcast_ref::<&str>()
+                .map(ToString::to_string)
+                .or_else(|| info.payload().downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic payload".into());
+
+            let message = match info.location() {
+                Some(location) => format!("{payload} ({location})"),
+                None => payload,
+            };
+
+            CAPTURED_PANIC.with(|captured| *captured.borrow_mut() = Some(message));
+        }));
+    });
+}
+
+/// Executes `func` once on a separate thread and measures its execution time and heap usage.
+/// Panics are captured instead of printed. On timeout, the thread is left running in the background.
+fn run_isolated<I, T>(
+    func: impl Fn(I) -> T + Send + 'static,
+    input: I,
+    timeout: Option<Duration>,
+) -> Result<(T, Duration, Option<AllocStats>), PartFailure>
+where
+    I: Send + 'static,
+    T: Send + 'static,
+{
+    install_panic_hook();
+
+    let (sender, receiver) = mpsc::channel();
+
+    let spawned = thread::Builder::new()
+        .stack_size(PART_STACK_SIZE)
+        .spawn(move || {
+            CAPTURE_PANICS.with(|capture| capture.set(true));
+
+            let measurement = AllocMeasurement::start();
+            let timer = Instant::now();
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                #[cfg(feature = "dhat-heap")]
+                let _profiler = dhat::Profiler::new_heap();
+
+                func(input)
+            }));
+            let elapsed = timer.elapsed();
+
+            let message = result
+                .map(|result| (result, elapsed, measurement.finish()))
+                .map_err(|_| {
+                    CAPTURED_PANIC
+                        .with(|captured| captured.borrow_mut().take())
+                        .unwrap_or_else(|| "unknown panic".into())
+                });
+            let _ = sender.send(message);
+        });
+
+    let received = match (spawned, timeout) {
+        (Err(e), _) => {
+            return Err(PartFailure::Panicked(format!(
+                "could not spawn thread: {e}"
+            )));
+        }
+        (Ok(_), Some(timeout)) => receiver.recv_timeout(timeout),
+        (Ok(_), None) => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
     };
 
-    (result, run.0, run.1)
+    match received {
+        Ok(Ok(received)) => Ok(received),
+        Ok(Err(message)) => Err(PartFailure::Panicked(message)),
+        Err(RecvTimeoutError::Timeout) => Err(PartFailure::TimedOut(timeout.unwrap_or_default())),
+        Err(RecvTimeoutError::Disconnected) => Err(PartFailure::Panicked("unknown panic".into())),
+    }
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    config: &BenchConfig,
+) -> BenchStats {
     let mut stdout = stdout();
 
+    let mut base_time = *base_time;
+
+    if !config.warmup.is_zero() {
+        print!(" > {ANSI_ITALIC}warming up{ANSI_RESET}");
+        let _ = stdout.flush();
+
+        // use the warm-up runs for a better estimate of the per-iteration time.
+        let timer = Instant::now();
+        let mut warmup_iterations: u32 = 0;
+        while timer.elapsed() < config.warmup {
+            black_box(func(black_box(input)));
+            warmup_iterations += 1;
+        }
+        base_time = timer.elapsed() / warmup_iterations.max(1);
+    }
+
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    let bench_iterations = config.iterations(&base_time);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -67,34 +650,79 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
-}
+fn format_stats(stats: &BenchStats) -> String {
+    let BenchStats {
+        mean,
+        median,
+        min,
+        max,
+        stddev,
+        p95,
+        samples,
+    } = stats;
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+    if *samples == 1 {
+        format!(" ({mean:.1?})")
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(
+            " ({mean:.1?} @ {samples} samples) [median {median:.1?}, min {min:.1?}, max {max:.1?}, stddev {stddev:.1?}, p95 {p95:.1?}]"
+        )
+    }
+}
+
+fn format_alloc(alloc: Option<&AllocStats>) -> String {
+    alloc.map_or_else(String::new, |alloc| format!(" [{}]", alloc.summary()))
+}
+
+fn format_verification(correct: Option<bool>) -> String {
+    match correct {
+        Some(true) => " ✔".into(),
+        Some(false) => format!(" {ANSI_BOLD}✖ (regressed){ANSI_RESET}"),
+        None => String::new(),
+    }
+}
+
+/// Samples a part while running it repeatedly and writes its flamegraph to `data/<year>/profiles`.
+fn profile_part<I: Copy, T>(func: impl Fn(I) -> T, input: I, puzzle: Puzzle, part: u8) {
+    let paths = ProfilePaths::new(puzzle, part);
+
+    print!("Part {part}: {ANSI_ITALIC}profiling{ANSI_RESET}");
+    let _ = stdout().flush();
+
+    match profiler::profile_part(func, input, &format!("{puzzle} part {part}"), &paths) {
+        Ok(samples) => {
+            print!("\r");
+            println!(
+                "🎄 Wrote flamegraph of part {part} ({samples} samples) to \"{}\"",
+                paths.svg.display()
+            );
+        }
+        Err(e) => {
+            print!("\r");
+            eprintln!("Failed to profile part {part}: {e}");
+        }
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+/// Remember a correct answer so later runs can be verified against it.
+fn store_answer(puzzle: Puzzle, part: u8, answer: &str) {
+    let mut answers = Answers::read_from_file(puzzle);
+    answers.set(part, answer);
+
+    match answers.store_file(puzzle) {
+        Ok(()) => println!("Stored answer for part {part}."),
+        Err(e) => eprintln!("Failed to store answer: {e}"),
+    }
+}
+
+fn print_result<T: Display>(result: &Result<Option<&T>, String>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
-        Some(result) => {
+        Ok(Some(result)) => {
             if result.to_string().contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
@@ -114,7 +742,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        Ok(None) => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
@@ -122,44 +750,215 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 println!("{part}: ✖             ");
             }
         }
+        Err(message) => {
+            let str = format!("{part}: {ANSI_BOLD}error: {message}{ANSI_RESET}");
+            if is_intermediate_result {
+                print!("{str}");
+            } else {
+                print!("\r");
+                println!("{str}{duration_str}");
+            }
+        }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+/// Submit one part of the solution to the Advent of Code website.
+/// Submits that are known to fail from earlier attempts in `data/<year>/submissions` or during the cooldown in `data/cooldown.json` are refused.
+fn submit_result(answer: &str, puzzle: Puzzle, part: u8) {
+    let logs = Cooldown::read_from_file()
+        .and_then(|cooldown| Ok((cooldown, Submissions::read_from_file(puzzle)?)));
 
-    if !args.contains(&"--submit".into()) {
-        return None;
+    let (mut cooldown, mut submissions) = match logs {
+        Ok(logs) => logs,
+        Err(e) => {
+            eprintln!("failed to read submissions log: {e}");
+            return;
+        }
+    };
+
+    if let Err(reason) = cooldown
+        .check(submissions::now())
+        .and_then(|()| submissions.check(part, answer))
+    {
+        println!("{ANSI_BOLD}Not submitting:{ANSI_RESET} {reason}");
+        return;
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    println!("Submitting result...");
+    let verdict = match aoc_client::submit(puzzle, part, answer) {
+        Ok(verdict) => verdict,
+        Err(e) => {
+            eprintln!("failed to submit answer: {e}");
+            return;
+        }
+    };
+
+    println!("{}", format_verdict(&verdict));
+
+    if verdict == SubmissionVerdict::Correct {
+        store_answer(puzzle, part, answer);
+
+        // part two is only included in the puzzle description once part one is solved.
+        if part == 1 {
+            match aoc_client::download_puzzle(puzzle) {
+                Ok(_) => println!("🎄 Updated puzzle description with part two"),
+                Err(e) => eprintln!("Failed to update puzzle description: {e}"),
+            }
+        }
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    if let Some((verdict, wait)) = Verdict::from_submission(&verdict) {
+        submissions.record(part, answer, verdict, wait);
+        if let Err(e) = submissions.store_file(puzzle) {
+            eprintln!("Failed to store submission: {e}");
+        }
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    };
+        if let Some(wait) = wait {
+            cooldown.start(submissions::now(), wait);
+            if let Err(e) = cooldown.store_file() {
+                eprintln!("Failed to store cooldown: {e}");
+            }
+        }
+    }
+}
+
+fn format_verdict(verdict: &SubmissionVerdict) -> String {
+    let wait_str = |wait: &Duration| format!("wait {}s before trying again", wait.as_secs());
+
+    match verdict {
+        SubmissionVerdict::Correct => format!("{ANSI_BOLD}{ANSI_GREEN}✔ Correct!{ANSI_RESET}"),
+        SubmissionVerdict::Incorrect { hint, wait } => {
+            let hint_str = match hint {
+                Some(Hint::TooHigh) => " (too high)",
+                Some(Hint::TooLow) => " (too low)",
+                None => "",
+            };
+            let wait_str = wait
+                .as_ref()
+                .map_or(String::new(), |wait| format!(", {}", wait_str(wait)));
+            format!("{ANSI_BOLD}{ANSI_RED}✖ Incorrect{hint_str}{ANSI_RESET}{wait_str}")
+        }
+        SubmissionVerdict::RateLimited { wait } => format!(
+            "{ANSI_BOLD}{ANSI_YELLOW}⏱ Rate limited{ANSI_RESET}, {}",
+            wait_str(wait)
+        ),
+        SubmissionVerdict::AlreadySolved => {
+            format!("{ANSI_BOLD}{ANSI_YELLOW}✔ Already solved{ANSI_RESET}")
+        }
+        SubmissionVerdict::WrongLevel => format!(
+            "{ANSI_BOLD}{ANSI_YELLOW}✖ Wrong level{ANSI_RESET}, this part is not unlocked yet"
+        ),
+    }
+}
 
-    if part_submit != part {
-        return None;
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{run_isolated, BenchConfig, BenchStats, PartFailure, RunOptions};
+    use std::time::Duration;
+
+    #[test]
+    fn captures_panics() {
+        let result = run_isolated(|x: u32| -> u32 { panic!("boom {x}") }, 1, None);
+        let Err(PartFailure::Panicked(message)) = result else {
+            panic!("expected a panic to be captured.");
+        };
+        assert!(message.starts_with("boom 1"));
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    #[test]
+    fn enforces_timeouts() {
+        let timeout = Duration::from_millis(10);
+        let result = run_isolated(
+            |x: u64| std::thread::sleep(Duration::from_millis(x)),
+            1000,
+            Some(timeout),
+        );
+        assert_eq!(result, Err(PartFailure::TimedOut(timeout)));
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    #[test]
+    fn returns_results_within_timeout() {
+        let result = run_isolated(|x: u32| x * 2, 21, Some(Duration::from_secs(10)));
+        assert_eq!(result.unwrap().0, 42);
+    }
+
+    #[test]
+    fn parses_run_options() {
+        let args: Vec<String> = ["01", "--json", "--submit", "2"].map(String::from).to_vec();
+        let options = RunOptions::from_args(&args);
+        assert_eq!(options.bench, None);
+        assert!(options.json);
+        assert_eq!(options.submit, Some(2));
+
+        let args: Vec<String> = ["01", "--time", "--min-samples", "3"]
+            .map(String::from)
+            .to_vec();
+        let options = RunOptions::from_args(&args);
+        assert_eq!(options.bench.unwrap().min_samples, 3);
+        assert!(!options.json);
+    }
+
+    #[test]
+    fn computes_bench_stats() {
+        let samples: Vec<Duration> = [4, 1, 3, 2, 10].map(Duration::from_nanos).to_vec();
+        let stats = BenchStats::from_samples(&samples);
+
+        assert_eq!(stats.mean, Duration::from_nanos(4));
+        assert_eq!(stats.median, Duration::from_nanos(3));
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.max, Duration::from_nanos(10));
+        assert_eq!(stats.stddev, Duration::from_nanos(3));
+        assert_eq!(stats.p95, Duration::from_nanos(10));
+        assert_eq!(stats.samples, 5);
+    }
+
+    #[test]
+    fn resolves_bench_config_from_args() {
+        let args: Vec<String> = ["--time", "--warmup", "500", "--bench-time", "5000"]
+            .map(String::from)
+            .to_vec();
+        let config = BenchConfig::default().with_overrides(None, None, Some("20"), Some("foo"));
+        assert_eq!(config.min_samples, 20);
+        assert_eq!(config.max_samples, 10000);
+
+        let config = BenchConfig::from_env_and_args(&args);
+        assert_eq!(config.warmup, Duration::from_millis(500));
+        assert_eq!(config.bench_time, Duration::from_secs(5));
+    }
+
+    #[test]
+    fn clamps_bench_iterations() {
+        let config = BenchConfig {
+            min_samples: 50,
+            max_samples: 20,
+            ..BenchConfig::default()
+        };
+        assert_eq!(config.iterations(&Duration::from_secs(2)), 50);
+        assert_eq!(
+            BenchConfig::default().iterations(&Duration::from_secs(2)),
+            10
+        );
+        assert_eq!(
+            BenchConfig::default().iterations(&Duration::from_nanos(1)),
+            10000
+        );
+        assert_eq!(
+            BenchConfig::default().iterations(&Duration::from_millis(1)),
+            1000
+        );
+    }
+
+    #[test]
+    fn forwards_bench_config_as_args() {
+        let config = BenchConfig {
+            warmup: Duration::from_millis(200),
+            ..BenchConfig::default()
+        };
+        assert_eq!(BenchConfig::from_env_and_args(&config.to_args()), config);
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert_eq!(BenchStats::from_samples(&[]), BenchStats::default());
+    }
 }
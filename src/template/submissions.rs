@@ -0,0 +1,507 @@
+/// Local log of submitted answers, used to refuse submits that are known to fail before they reach the website.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{Error, ErrorKind},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::aoc_client::{Hint, SubmissionVerdict};
+use crate::template::Puzzle;
+
+static SUBMISSIONS_FOLDER: &str = "submissions";
+static COOLDOWN_FILE_PATH: &str = "./data/cooldown.json";
+
+/// The verdict of the website on a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Incorrect, without a hint.
+    Incorrect,
+    /// The answer was not checked because of a cooldown.
+    RateLimited,
+}
+
+impl Verdict {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Incorrect => "incorrect",
+            Verdict::RateLimited => "rate_limited",
+        }
+    }
+
+    /// Converts the verdict of the website to the stored verdict and cooldown.
+    /// Returns `None` for verdicts that did not check an answer, e.g. for an already solved part.
+    pub fn from_submission(verdict: &SubmissionVerdict) -> Option<(Self, Option<Duration>)> {
+        match *verdict {
+            SubmissionVerdict::Correct => Some((Verdict::Correct, None)),
+            SubmissionVerdict::Incorrect { hint, wait } => {
+                let verdict = match hint {
+                    Some(Hint::TooHigh) => Verdict::TooHigh,
+                    Some(Hint::TooLow) => Verdict::TooLow,
+                    None => Verdict::Incorrect,
+                };
+                Some((verdict, wait))
+            }
+            SubmissionVerdict::RateLimited { wait } => Some((Verdict::RateLimited, Some(wait))),
+            SubmissionVerdict::AlreadySolved | SubmissionVerdict::WrongLevel => None,
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too_high" => Ok(Verdict::TooHigh),
+            "too_low" => Ok(Verdict::TooLow),
+            "incorrect" => Ok(Verdict::Incorrect),
+            "rate_limited" => Ok(Verdict::RateLimited),
+            _ => Err(format!("Unknown verdict `{s}`.")),
+        }
+    }
+}
+
+/// A single submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attempt {
+    pub part: u8,
+    pub answer: String,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub verdict: Verdict,
+    /// Cooldown the website imposed after this attempt.
+    pub wait: Option<Duration>,
+}
+
+/// Why a submit was refused locally.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Blocked {
+    AlreadySolved(String),
+    Repeated(Verdict),
+    TooHigh(String),
+    TooLow(String),
+    CoolingDown(Duration),
+}
+
+impl Display for Blocked {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Blocked::AlreadySolved(answer) => {
+                write!(f, "this part was already solved with \"{answer}\".")
+            }
+            Blocked::Repeated(verdict) => write!(
+                f,
+                "this answer was submitted before and rejected ({}).",
+                verdict.as_str()
+            ),
+            Blocked::TooHigh(bound) => {
+                write!(
+                    f,
+                    "the answer must be lower than \"{bound}\", which was too high."
+                )
+            }
+            Blocked::TooLow(bound) => {
+                write!(
+                    f,
+                    "the answer must be higher than \"{bound}\", which was too low."
+                )
+            }
+            Blocked::CoolingDown(wait) => {
+                write!(f, "please wait {}s before trying again.", wait.as_secs())
+            }
+        }
+    }
+}
+
+/// All attempts of a single day, oldest first.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Submissions {
+    pub attempts: Vec<Attempt>,
+}
+
+impl Submissions {
+    fn get_path(puzzle: Puzzle) -> PathBuf {
+        puzzle.data_path(SUBMISSIONS_FOLDER, "json")
+    }
+
+    /// Rehydrate submissions from a JSON file. If not present, returns an empty log.
+    /// A log that can't be read is an error, since ignoring it would lift the checks of [`Submissions::check`].
+    pub fn read_from_file(puzzle: Puzzle) -> Result<Self, String> {
+        let path = Self::get_path(puzzle);
+        read_optional(&path)?.map_or(Ok(Self::default()), |json| {
+            Submissions::try_from(json).map_err(|e| format!("{}: {e}", path.display()))
+        })
+    }
+
+    /// Dehydrate submissions to a JSON file.
+    pub fn store_file(&self, puzzle: Puzzle) -> Result<(), Error> {
+        fs::create_dir_all(puzzle.data_dir(SUBMISSIONS_FOLDER))?;
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(Self::get_path(puzzle))?;
+        json.format_to(&mut file)
+    }
+
+    /// Records an attempt at the current time.
+    pub fn record(&mut self, part: u8, answer: &str, verdict: Verdict, wait: Option<Duration>) {
+        self.attempts.push(Attempt {
+            part,
+            answer: answer.into(),
+            timestamp: now(),
+            verdict,
+            wait,
+        });
+    }
+
+    /// Checks whether submitting `answer` for `part` can succeed, according to earlier attempts.
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), Blocked> {
+        let attempts = self.attempts.iter().filter(|x| x.part == part);
+
+        for attempt in attempts.clone() {
+            match attempt.verdict {
+                Verdict::Correct => return Err(Blocked::AlreadySolved(attempt.answer.clone())),
+                Verdict::RateLimited => {}
+                verdict if attempt.answer == answer => return Err(Blocked::Repeated(verdict)),
+                _ => {}
+            }
+        }
+
+        let Ok(value) = answer.trim().parse::<i128>() else {
+            return Ok(());
+        };
+
+        let bound = |verdict: Verdict| {
+            attempts
+                .clone()
+                .filter(move |x| x.verdict == verdict)
+                .filter_map(|x| Some((x.answer.trim().parse::<i128>().ok()?, &x.answer)))
+        };
+
+        if let Some((_, answer)) = bound(Verdict::TooHigh)
+            .filter(|(high, _)| value >= *high)
+            .min_by_key(|(high, _)| *high)
+        {
+            return Err(Blocked::TooHigh(answer.clone()));
+        }
+
+        if let Some((_, answer)) = bound(Verdict::TooLow)
+            .filter(|(low, _)| value <= *low)
+            .max_by_key(|(low, _)| *low)
+        {
+            return Err(Blocked::TooLow(answer.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+/// The cooldown imposed by the website after a wrong answer.
+/// It applies to the whole account, so it is stored once for all puzzles and years.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Cooldown {
+    /// Seconds since the unix epoch, `0` if there never was a cooldown.
+    pub until: u64,
+}
+
+impl Cooldown {
+    /// Rehydrate the cooldown from a JSON file. If not present, returns no cooldown.
+    pub fn read_from_file() -> Result<Self, String> {
+        read_optional(COOLDOWN_FILE_PATH.as_ref())?.map_or(Ok(Self::default()), |json| {
+            Cooldown::try_from(json).map_err(|e| format!("{COOLDOWN_FILE_PATH}: {e}"))
+        })
+    }
+
+    /// Dehydrate the cooldown to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        fs::create_dir_all("./data")?;
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(COOLDOWN_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Starts a cooldown of `wait` at `timestamp`, unless a longer one is running.
+    pub fn start(&mut self, timestamp: u64, wait: Duration) {
+        self.until = self.until.max(timestamp + wait.as_secs());
+    }
+
+    /// Checks whether the cooldown has passed at `timestamp`.
+    pub fn check(&self, timestamp: u64) -> Result<(), Blocked> {
+        if self.until > timestamp {
+            return Err(Blocked::CoolingDown(Duration::from_secs(
+                self.until - timestamp,
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Reads a file, `None` if it does not exist.
+fn read_optional(path: &Path) -> Result<Option<String>, String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("{}: {e}", path.display())),
+    }
+}
+
+/// Seconds since the unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submissions> for JsonValue {
+    fn from(value: &Submissions) -> Self {
+        let attempts = value
+            .attempts
+            .iter()
+            .map(|attempt| {
+                let mut map: HashMap<String, JsonValue> = HashMap::new();
+                map.insert("part".into(), JsonValue::Number(f64::from(attempt.part)));
+                map.insert("answer".into(), JsonValue::String(attempt.answer.clone()));
+                map.insert(
+                    "timestamp".into(),
+                    JsonValue::Number(attempt.timestamp as f64),
+                );
+                map.insert(
+                    "verdict".into(),
+                    JsonValue::String(attempt.verdict.as_str().into()),
+                );
+                map.insert(
+                    "wait_seconds".into(),
+                    attempt
+                        .wait
+                        .map_or(JsonValue::Null, |x| JsonValue::Number(x.as_secs() as f64)),
+                );
+                JsonValue::Object(map)
+            })
+            .collect();
+
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert("attempts".into(), JsonValue::Array(attempts));
+        JsonValue::Object(map)
+    }
+}
+
+impl From<&Cooldown> for JsonValue {
+    fn from(value: &Cooldown) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert("until".into(), JsonValue::Number(value.until as f64));
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Cooldown {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let until = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("until")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("expected `json.until` to be a number.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(Cooldown {
+            until: *until as u64,
+        })
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let attempts = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("attempts")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("expected `json.attempts` to be an array.")?;
+
+        Ok(Submissions {
+            attempts: attempts
+                .iter()
+                .map(Attempt::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<&JsonValue> for Attempt {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected attempt to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected attempt.{key} to be a number."))
+        };
+
+        let string = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>().cloned())
+                .ok_or(format!("Expected attempt.{key} to be a string."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let wait = json
+            .get("wait_seconds")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| Duration::from_secs(*x as u64));
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(Attempt {
+            part: number("part")? as u8,
+            answer: string("answer")?,
+            timestamp: number("timestamp")? as u64,
+            verdict: string("verdict")?.parse()?,
+            wait,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Attempt, Blocked, Cooldown, Submissions, Verdict};
+    use crate::template::aoc_client::{Hint, SubmissionVerdict};
+    use std::time::Duration;
+    use tinyjson::JsonValue;
+
+    fn attempt(part: u8, answer: &str, verdict: Verdict, wait: Option<u64>) -> Attempt {
+        Attempt {
+            part,
+            answer: answer.into(),
+            timestamp: 1000,
+            verdict,
+            wait: wait.map(Duration::from_secs),
+        }
+    }
+
+    #[test]
+    fn converts_submission_verdicts() {
+        assert_eq!(
+            Verdict::from_submission(&SubmissionVerdict::Incorrect {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60))
+            }),
+            Some((Verdict::TooHigh, Some(Duration::from_secs(60))))
+        );
+        assert_eq!(
+            Verdict::from_submission(&SubmissionVerdict::RateLimited {
+                wait: Duration::from_secs(80)
+            }),
+            Some((Verdict::RateLimited, Some(Duration::from_secs(80))))
+        );
+        assert_eq!(
+            Verdict::from_submission(&SubmissionVerdict::AlreadySolved),
+            None
+        );
+    }
+
+    #[test]
+    fn blocks_rejected_answers() {
+        let submissions = Submissions {
+            attempts: vec![attempt(1, "abc", Verdict::Incorrect, None)],
+        };
+        assert_eq!(
+            submissions.check(1, "abc"),
+            Err(Blocked::Repeated(Verdict::Incorrect))
+        );
+        assert_eq!(submissions.check(1, "abd"), Ok(()));
+        assert_eq!(submissions.check(2, "abc"), Ok(()));
+    }
+
+    #[test]
+    fn blocks_answers_outside_bounds() {
+        let submissions = Submissions {
+            attempts: vec![
+                attempt(1, "100", Verdict::TooHigh, None),
+                attempt(1, "80", Verdict::TooHigh, None),
+                attempt(1, "20", Verdict::TooLow, None),
+            ],
+        };
+        assert_eq!(
+            submissions.check(1, "90"),
+            Err(Blocked::TooHigh("80".into()))
+        );
+        assert_eq!(
+            submissions.check(1, "10"),
+            Err(Blocked::TooLow("20".into()))
+        );
+        assert_eq!(submissions.check(1, "50"), Ok(()));
+    }
+
+    #[test]
+    fn blocks_during_cooldown() {
+        let mut cooldown = Cooldown::default();
+        assert_eq!(cooldown.check(1000), Ok(()));
+
+        cooldown.start(1000, Duration::from_secs(60));
+        cooldown.start(1010, Duration::from_secs(10));
+        assert_eq!(
+            cooldown.check(1030),
+            Err(Blocked::CoolingDown(Duration::from_secs(30)))
+        );
+        assert_eq!(cooldown.check(1060), Ok(()));
+    }
+
+    #[test]
+    fn rejects_malformed_files() {
+        assert!(Submissions::try_from("{\"attempts\": 1}".to_string()).is_err());
+        assert!(Cooldown::try_from("[]".to_string()).is_err());
+    }
+
+    #[test]
+    fn blocks_solved_parts() {
+        let submissions = Submissions {
+            attempts: vec![attempt(1, "42", Verdict::Correct, None)],
+        };
+        assert_eq!(
+            submissions.check(1, "43"),
+            Err(Blocked::AlreadySolved("42".into()))
+        );
+    }
+
+    #[test]
+    fn roundtrips_submissions() {
+        let submissions = Submissions {
+            attempts: vec![
+                attempt(1, "42", Verdict::TooLow, Some(60)),
+                attempt(2, "a\nb", Verdict::Correct, None),
+            ],
+        };
+        let json = JsonValue::from(&submissions).stringify().unwrap();
+        assert_eq!(Submissions::try_from(json).unwrap(), submissions);
+
+        let cooldown = Cooldown { until: 1060 };
+        let json = JsonValue::from(&cooldown).stringify().unwrap();
+        assert_eq!(Cooldown::try_from(json).unwrap(), cooldown);
+    }
+}
@@ -1,20 +1,37 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fs, io::Error, str::FromStr, time::Duration};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{
+    alloc::AllocStats, report::PartStatus, runner::BenchStats, Day, Puzzle, Year,
+};
 
 static TIMINGS_FILE_PATH: &str = "./data/timings.json";
 
-/// Represents benchmark times for a single day.
+/// Represents benchmark times for a single puzzle.
 #[derive(Clone, Debug)]
 pub struct Timing {
-    pub day: Day,
+    pub puzzle: Puzzle,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub part_1_stats: Option<BenchStats>,
+    pub part_2_stats: Option<BenchStats>,
+    /// Outcome of the last run of a part, e.g. to tell timed out parts apart from unsolved ones.
+    pub part_1_status: Option<PartStatus>,
+    pub part_2_status: Option<PartStatus>,
+    /// Heap usage of a part, only recorded when built with the `count-allocations` feature.
+    pub part_1_alloc: Option<AllocStats>,
+    pub part_2_alloc: Option<AllocStats>,
     pub total_nanos: f64,
 }
 
-/// Represents benchmark times for a set of days.
+impl Timing {
+    /// Checks whether all parts of the puzzle were benched.
+    pub fn is_complete(&self) -> bool {
+        self.part_1.is_some() && (self.puzzle.part_count() < 2 || self.part_2.is_some())
+    }
+}
+
+/// Represents benchmark times for a set of puzzles, possibly of several years.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
 pub struct Timings {
@@ -46,12 +63,12 @@ impl Timings {
         }
 
         for timing in &self.data {
-            if !data.iter().any(|t| t.day == timing.day) {
+            if !data.iter().any(|t| t.puzzle == timing.puzzle) {
                 data.push(timing.clone());
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.puzzle);
         Timings { data }
     }
 
@@ -60,10 +77,21 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
+    /// Returns the stats of a part, if that part was benched.
+    pub fn part_stats(&self, puzzle: Puzzle, part: u8) -> Option<&BenchStats> {
+        let timing = self.data.iter().find(|t| t.puzzle == puzzle)?;
+        match part {
+            1 => timing.part_1_stats.as_ref(),
+            2 => timing.part_2_stats.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Checks whether all parts of a puzzle were benched. Single-part puzzles are complete without part two.
+    pub fn is_day_complete(&self, puzzle: Puzzle) -> bool {
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.puzzle == puzzle && t.is_complete())
     }
 }
 
@@ -87,7 +115,14 @@ impl TryFrom<String> for Timings {
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+        Timings::try_from(&json)
+    }
+}
 
+impl TryFrom<&JsonValue> for Timings {
+    type Error = String;
+
+    fn try_from(json: &JsonValue) -> Result<Self, Self::Error> {
         let json_data = json
             .get::<HashMap<String, JsonValue>>()
             .ok_or("expected JSON document to be an object.")?
@@ -111,7 +146,14 @@ impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
-        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert(
+            "year".into(),
+            JsonValue::String(value.puzzle.year.to_string()),
+        );
+        map.insert(
+            "day".into(),
+            JsonValue::String(value.puzzle.day.to_string()),
+        );
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
         let part_1 = value.part_1.clone().map(JsonValue::String);
@@ -133,6 +175,42 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        map.insert(
+            "part_1_stats".into(),
+            value
+                .part_1_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_2_stats".into(),
+            value
+                .part_2_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        for (key, alloc) in [
+            ("part_1_alloc", value.part_1_alloc),
+            ("part_2_alloc", value.part_2_alloc),
+        ] {
+            map.insert(
+                key.into(),
+                alloc.as_ref().map_or(JsonValue::Null, JsonValue::from),
+            );
+        }
+
+        for (key, status) in [
+            ("part_1_status", value.part_1_status),
+            ("part_2_status", value.part_2_status),
+        ] {
+            map.insert(
+                key.into(),
+                status.map_or(JsonValue::Null, |x| JsonValue::String(x.as_str().into())),
+            );
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -151,6 +229,16 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
+        // NOTE: timings were stored without a year before multi-year support, these are timings of `AOC_YEAR`.
+        let year = match json.get("year") {
+            None => Year::from_env()
+                .ok_or("Expected `AOC_YEAR` to be set for timings without a year.")?,
+            Some(v) => v
+                .get::<String>()
+                .and_then(|year| Year::from_str(year).ok())
+                .ok_or("Expected timing.year to be a Year struct.")?,
+        };
+
         let part_1 = json
             .get("part_1")
             .map(|v| if v.is_null() { None } else { v.get::<String>() })
@@ -166,10 +254,47 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // NOTE: statistics were added later, treat them as optional for older timing files.
+        let part_1_stats = match json.get("part_1_stats") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(BenchStats::try_from(v)?),
+        };
+
+        let part_2_stats = match json.get("part_2_stats") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(BenchStats::try_from(v)?),
+        };
+
+        // NOTE: statuses were added later as well.
+        let status = |key: &str| match json.get(key) {
+            None => Ok(None),
+            Some(v) if v.is_null() => Ok(None),
+            Some(v) => v
+                .get::<String>()
+                .ok_or(format!("Expected timing.{key} to be null or string."))
+                .and_then(|x| x.parse::<PartStatus>())
+                .map(Some),
+        };
+
+        // NOTE: heap usage is only recorded when built with the `count-allocations` feature.
+        let alloc = |key: &str| match json.get(key) {
+            None => Ok(None),
+            Some(v) if v.is_null() => Ok(None),
+            Some(v) => AllocStats::try_from(v).map(Some),
+        };
+
         Ok(Timing {
-            day,
+            puzzle: Puzzle::new(year, day),
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            part_1_stats,
+            part_2_stats,
+            part_1_status: status("part_1_status")?,
+            part_2_status: status("part_2_status")?,
+            part_1_alloc: alloc("part_1_alloc")?,
+            part_2_alloc: alloc("part_2_alloc")?,
             total_nanos,
         })
     }
@@ -177,9 +302,102 @@ impl TryFrom<&JsonValue> for Timing {
 
 /* -------------------------------------------------------------------------- */
 
+impl From<&BenchStats> for JsonValue {
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        for (key, duration) in [
+            ("mean_nanos", value.mean),
+            ("median_nanos", value.median),
+            ("min_nanos", value.min),
+            ("max_nanos", value.max),
+            ("stddev_nanos", value.stddev),
+            ("p95_nanos", value.p95),
+        ] {
+            map.insert(key.into(), JsonValue::Number(duration.as_nanos() as f64));
+        }
+
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected timing stats.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let duration = |key: &str| number(key).map(|x| Duration::from_nanos(x as u64));
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(BenchStats {
+            mean: duration("mean_nanos")?,
+            median: duration("median_nanos")?,
+            min: duration("min_nanos")?,
+            max: duration("max_nanos")?,
+            stddev: duration("stddev_nanos")?,
+            p95: duration("p95_nanos")?,
+            samples: number("samples")? as u128,
+        })
+    }
+}
+
+impl From<&AllocStats> for JsonValue {
+    fn from(value: &AllocStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        for (key, count) in [
+            ("allocations", value.allocations),
+            ("bytes", value.bytes),
+            ("peak_bytes", value.peak),
+        ] {
+            map.insert(key.into(), JsonValue::Number(count as f64));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for AllocStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected alloc stats to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let count = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .map(|x| x as u64)
+                .ok_or(format!("Expected alloc stats.{key} to be a number."))
+        };
+
+        Ok(AllocStats {
+            allocations: count("allocations")?,
+            bytes: count("bytes")?,
+            peak: count("peak_bytes")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 #[cfg(feature = "test_lib")]
 mod tests {
-    use crate::day;
+    use crate::{day, template::Puzzle, year};
 
     use super::{Timing, Timings};
 
@@ -187,21 +405,39 @@ mod tests {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
+                    puzzle: Puzzle::new(year!(2024), day!(1)),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
                     total_nanos: 3e+10,
                 },
                 Timing {
-                    day: day!(2),
+                    puzzle: Puzzle::new(year!(2024), day!(2)),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
                     total_nanos: 7e+10,
                 },
                 Timing {
-                    day: day!(4),
+                    puzzle: Puzzle::new(year!(2024), day!(4)),
                     part_1: Some("40ms".into()),
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
                     total_nanos: 4e+10,
                 },
             ],
@@ -209,20 +445,51 @@ mod tests {
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{
+            day, template::alloc::AllocStats, template::timings::Timings, template::Puzzle, year,
+        };
+        use std::time::Duration;
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "data": [{ "year": "2023", "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
-            assert_eq!(timing.day, day!(1));
+            assert_eq!(timing.puzzle, Puzzle::new(year!(2023), day!(1)));
             assert_eq!(timing.part_1, Some("1ms".to_string()));
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_timing_stats() {
+            let json = r#"{ "data": [{ "year": "2024", "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "mean_nanos": 1000000, "median_nanos": 900000, "min_nanos": 800000, "max_nanos": 2000000, "stddev_nanos": 100000, "p95_nanos": 1500000, "samples": 100 }, "part_2_stats": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1_stats.unwrap();
+            assert_eq!(stats.median, Duration::from_micros(900));
+            assert_eq!(stats.p95, Duration::from_micros(1500));
+            assert_eq!(stats.samples, 100);
+            assert_eq!(timing.part_2_stats, None);
+        }
+
+        #[test]
+        fn handles_json_alloc_stats() {
+            let json = r#"{ "data": [{ "year": "2024", "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_alloc": { "allocations": 12, "bytes": 4096, "peak_bytes": 1024 }, "part_2_alloc": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(
+                timing.part_1_alloc,
+                Some(AllocStats {
+                    allocations: 12,
+                    bytes: 4096,
+                    peak: 1024,
+                })
+            );
+            assert_eq!(timing.part_2_alloc, None);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -272,48 +539,100 @@ mod tests {
         use crate::{
             day,
             template::timings::{Timing, Timings},
+            template::Puzzle,
+            year,
         };
 
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
+                    puzzle: Puzzle::new(year!(2024), day!(1)),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
                     total_nanos: 3_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(
+                timings.is_day_complete(Puzzle::new(year!(2024), day!(1))),
+                true
+            );
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
+                    puzzle: Puzzle::new(year!(2024), day!(1)),
                     part_1: Some("1ms".into()),
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
                     total_nanos: 1_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(
+                timings.is_day_complete(Puzzle::new(year!(2024), day!(1))),
+                false
+            );
+        }
+
+        #[test]
+        fn handles_single_part_days() {
+            let timings = Timings {
+                data: vec![Timing {
+                    puzzle: Puzzle::new(year!(2024), day!(25)),
+                    part_1: Some("1ms".into()),
+                    part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
+                    total_nanos: 1_000_000_000_f64,
+                }],
+            };
+
+            assert_eq!(
+                timings.is_day_complete(Puzzle::new(year!(2024), day!(25))),
+                true
+            );
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
+                    puzzle: Puzzle::new(year!(2024), day!(1)),
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
                     total_nanos: 0.0,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(
+                timings.is_day_complete(Puzzle::new(year!(2024), day!(1))),
+                false
+            );
         }
     }
 
@@ -321,6 +640,8 @@ mod tests {
         use crate::{
             day,
             template::timings::{Timing, Timings},
+            template::Puzzle,
+            year,
         };
 
         use super::get_mock_timings;
@@ -330,18 +651,24 @@ mod tests {
             let timings = get_mock_timings();
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(3),
+                    puzzle: Puzzle::new(year!(2024), day!(3)),
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
                     total_nanos: 0_f64,
                 }],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
-            assert_eq!(merged.data[0].day, day!(1));
-            assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[2].day, day!(3));
-            assert_eq!(merged.data[3].day, day!(4));
+            assert_eq!(merged.data[0].puzzle.day, day!(1));
+            assert_eq!(merged.data[1].puzzle.day, day!(2));
+            assert_eq!(merged.data[2].puzzle.day, day!(3));
+            assert_eq!(merged.data[3].puzzle.day, day!(4));
         }
 
         #[test]
@@ -350,19 +677,50 @@ mod tests {
 
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(2),
+                    puzzle: Puzzle::new(year!(2024), day!(2)),
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
                     total_nanos: 0_f64,
                 }],
             };
             let merged = timings.merge(&other);
 
             assert_eq!(merged.data.len(), 3);
-            assert_eq!(merged.data[0].day, day!(1));
-            assert_eq!(merged.data[1].day, day!(2));
+            assert_eq!(merged.data[0].puzzle.day, day!(1));
+            assert_eq!(merged.data[1].puzzle.day, day!(2));
             assert_eq!(merged.data[1].total_nanos, 0_f64);
-            assert_eq!(merged.data[2].day, day!(4));
+            assert_eq!(merged.data[2].puzzle.day, day!(4));
+        }
+
+        #[test]
+        fn keeps_timings_of_other_years() {
+            let timings = get_mock_timings();
+
+            let other = Timings {
+                data: vec![Timing {
+                    puzzle: Puzzle::new(year!(2023), day!(2)),
+                    part_1: None,
+                    part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: None,
+                    part_2_status: None,
+                    part_1_alloc: None,
+                    part_2_alloc: None,
+                    total_nanos: 0_f64,
+                }],
+            };
+            let merged = timings.merge(&other);
+
+            assert_eq!(merged.data.len(), 4);
+            assert_eq!(merged.data[0].puzzle, Puzzle::new(year!(2023), day!(2)));
+            assert_eq!(merged.data[2].puzzle, Puzzle::new(year!(2024), day!(2)));
         }
 
         #[test]
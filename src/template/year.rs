@@ -0,0 +1,188 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::{all_days, Day};
+
+/// Environment variable that holds the default year, set in `.cargo/config.toml`.
+pub const YEAR_ENV: &str = "AOC_YEAR";
+
+/// The year of an Advent of Code event (i.e. 2015 or later).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// The year of the first event.
+    pub const FIRST: u16 = 2015;
+
+    /// Creates a [`Year`] from the provided value if it's a year with an event,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        (Self::FIRST..10000).contains(&year).then_some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Reads the default year from the `AOC_YEAR` environment variable.
+    pub fn from_env() -> Option<Self> {
+        env::var(YEAR_ENV).ok()?.parse().ok()
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// The last day of the event. Events run for 25 days, and for 12 days since 2025.
+    pub fn last_day(self) -> Day {
+        if self.0 >= 2025 {
+            Day::__new_unchecked(12)
+        } else {
+            Day::__new_unchecked(25)
+        }
+    }
+
+    /// Iterates over the days of the event, from the 1st to its [last day](Self::last_day).
+    pub fn days(self) -> impl Iterator<Item = Day> {
+        all_days().take(usize::from(self.last_day().into_inner()))
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting a year of {} or later", Year::FIRST)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Reads the year of a solution from the path of its module, e.g. `src/bin/2024-08.rs`.
+/// Falls back to `default` (the value of `AOC_YEAR` at compile time) if the file name has no year prefix.
+///
+/// Not part of the public API, used by the `solution!` macro in a const context.
+#[doc(hidden)]
+pub const fn __year_from_path(path: &str, default: Option<&str>) -> Year {
+    let bytes = path.as_bytes();
+
+    let mut start = bytes.len();
+    while start > 0 && bytes[start - 1] != b'/' && bytes[start - 1] != b'\\' {
+        start -= 1;
+    }
+
+    if let Some(year) = parse_year(bytes, start) {
+        return Year(year);
+    }
+
+    match default {
+        Some(default) => match parse_year(default.as_bytes(), 0) {
+            Some(year) => Year(year),
+            None => panic!("`AOC_YEAR` is not a valid year."),
+        },
+        None => panic!("could not determine the year of this solution, name the file like `2024-08.rs` or set `AOC_YEAR`."),
+    }
+}
+
+/// Parses four digits at `start`, followed by the end of the input or a `-`.
+const fn parse_year(bytes: &[u8], start: usize) -> Option<u16> {
+    if bytes.len() < start + 4 || (bytes.len() > start + 4 && bytes[start + 4] != b'-') {
+        return None;
+    }
+
+    let mut year = 0;
+    let mut i = start;
+    while i < start + 4 {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+        year = year * 10 + (bytes[i] - b'0') as u16;
+        i += 1;
+    }
+
+    if year < Year::FIRST {
+        return None;
+    }
+
+    Some(year)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= $crate::template::Year::FIRST && $year < 10000,
+            concat!("invalid year `", $year, "`, expecting 2015 or later"),
+        );
+        $crate::template::Year::__new_unchecked($year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{__year_from_path, Year};
+    use crate::day;
+
+    #[test]
+    fn reads_year_from_path() {
+        assert_eq!(
+            __year_from_path("src/bin/2023-08.rs", Some("2024")),
+            year!(2023)
+        );
+        assert_eq!(
+            __year_from_path("C:\\aoc\\src\\bin\\2023-08.rs", None),
+            year!(2023)
+        );
+        assert_eq!(__year_from_path("src/bin/08.rs", Some("2024")), year!(2024));
+        assert_eq!(
+            __year_from_path("/2023-aoc/src/bin/08.rs", Some("2024")),
+            year!(2024)
+        );
+    }
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2015".parse::<Year>().ok(), Some(year!(2015)));
+        assert_eq!(
+            Year::new(2024).map(|year| year.to_string()),
+            Some("2024".into())
+        );
+        assert!("2014".parse::<Year>().is_err());
+        assert!("24".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn iterates_days_of_events() {
+        assert_eq!(year!(2024).days().last(), Some(day!(25)));
+        assert_eq!(year!(2025).days().count(), 12);
+    }
+}
@@ -0,0 +1,335 @@
+use std::fmt;
+use std::iter::StepBy;
+use std::ops::{Index, IndexMut};
+use std::slice;
+
+use super::map::{Direction, Map, Position};
+
+/// A rectangular grid stored row by row in a single `Vec`.
+///
+/// Cells are addressed either by [`Position`] or by their index into the backing `Vec`.
+/// Index-based lookups avoid converting positions back and forth in hot loops.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    data: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid of the given size with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            data: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Creates a grid from cells in row order. Returns `None` if `data` does not fill whole rows.
+    pub fn from_vec(width: usize, data: Vec<T>) -> Option<Self> {
+        if width == 0 {
+            return data.is_empty().then_some(Self {
+                data,
+                width: 0,
+                height: 0,
+            });
+        }
+
+        if !data.len().is_multiple_of(width) {
+            return None;
+        }
+
+        let height = data.len() / width;
+        Some(Self {
+            data,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Index of a position into the backing `Vec`, `None` if it's out of bounds.
+    pub fn index_of(&self, position: &Position) -> Option<usize> {
+        let (x, y) = (position.x as usize, position.y as usize);
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+
+    /// Position of an index into the backing `Vec`.
+    pub fn position_of(&self, index: usize) -> Position {
+        Position {
+            x: (index % self.width) as u32,
+            y: (index / self.width) as u32,
+        }
+    }
+
+    /// Index of the neighbour of a cell in a direction, `None` at the edges of the grid.
+    pub fn neighbour_index(&self, index: usize, direction: Direction) -> Option<usize> {
+        let x = index % self.width;
+        match direction {
+            Direction::Up => index.checked_sub(self.width),
+            Direction::Down => Some(index + self.width).filter(|i| *i < self.data.len()),
+            Direction::Left => (x > 0).then(|| index - 1),
+            Direction::Right => (x + 1 < self.width).then(|| index + 1),
+        }
+    }
+
+    /// Indices of the orthogonal neighbours of a cell, in the same order as [`Map::neighbours`].
+    pub fn neighbour_indices(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        [
+            Direction::Left,
+            Direction::Up,
+            Direction::Down,
+            Direction::Right,
+        ]
+        .into_iter()
+        .filter_map(move |direction| self.neighbour_index(index, direction))
+    }
+
+    /// The cells of a row.
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.data[y * self.width..(y + 1) * self.width])
+    }
+
+    /// The cells of a row, mutably.
+    pub fn row_mut(&mut self, y: usize) -> Option<&mut [T]> {
+        (y < self.height).then(|| &mut self.data[y * self.width..(y + 1) * self.width])
+    }
+
+    /// The cells of a column, top to bottom.
+    pub fn column(&self, x: usize) -> Option<StepBy<slice::Iter<'_, T>>> {
+        (x < self.width).then(|| self.data[x..].iter().step_by(self.width))
+    }
+
+    /// Iterates over the rows of the grid.
+    pub fn rows(&self) -> slice::ChunksExact<'_, T> {
+        self.data.chunks_exact(self.width.max(1))
+    }
+
+    /// Iterates over the cells of the grid in row order.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Iterates mutably over the cells of the grid in row order.
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// Iterates over all positions of the grid in row order.
+    pub fn positions(&self) -> Positions {
+        Positions {
+            width: self.width,
+            len: self.data.len(),
+            index: 0,
+        }
+    }
+
+    /// The backing `Vec` of the grid, in row order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+}
+
+/// Iterator over the positions of a [`Grid`] in row order. Does not borrow the grid.
+#[derive(Debug, Clone)]
+pub struct Positions {
+    width: usize,
+    len: usize,
+    index: usize,
+}
+
+impl Iterator for Positions {
+    type Item = Position;
+
+    fn next(&mut self) -> Option<Position> {
+        if self.index >= self.len {
+            return None;
+        }
+
+        let position = Position {
+            x: (self.index % self.width) as u32,
+            y: (self.index / self.width) as u32,
+        };
+        self.index += 1;
+        Some(position)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Positions {}
+
+impl<T> TryFrom<Vec<Vec<T>>> for Grid<T> {
+    type Error = ();
+
+    /// Flattens rows into a grid. Fails if the rows have different lengths.
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return Err(());
+        }
+
+        let height = rows.len();
+        Ok(Self {
+            data: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        })
+    }
+}
+
+impl<T: Clone + PartialEq + Eq> Map<T> for Grid<T> {
+    fn dimensions(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    fn get(&self, position: &Position) -> Option<&T> {
+        self.index_of(position).map(|index| &self.data[index])
+    }
+
+    fn get_mut(&mut self, position: &Position) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.data[index])
+    }
+
+    fn position_iter(&self) -> Box<dyn Iterator<Item = Position>> {
+        Box::new(self.positions())
+    }
+
+    fn neighbours(&self, position: &Position) -> Vec<Position> {
+        self.index_of(position)
+            .map(|index| {
+                self.neighbour_indices(index)
+                    .map(|index| self.position_of(index))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn swap(&mut self, a: &Position, b: &Position) {
+        let (a, b) = (self.index_of(a).unwrap(), self.index_of(b).unwrap());
+        self.data.swap(a, b);
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        let index = self.index_of(&position).expect("position out of bounds");
+        &self.data[index]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let index = self.index_of(&position).expect("position out of bounds");
+        &mut self.data[index]
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.data[index]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.data[index]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Grid;
+    use crate::utils::map::{Direction, Map, Position};
+
+    fn get_mock_grid() -> Grid<char> {
+        Grid::try_from(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]).unwrap()
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        assert!(Grid::try_from(vec![vec![1, 2], vec![3]]).is_err());
+        assert!(Grid::from_vec(2, vec![1, 2, 3]).is_none());
+    }
+
+    #[test]
+    fn gets_cells_by_position() {
+        let grid = get_mock_grid();
+        assert_eq!(grid.dimensions(), (3, 2));
+        assert_eq!(grid.get(&Position { x: 2, y: 1 }), Some(&'f'));
+        assert_eq!(grid.get(&Position { x: 3, y: 0 }), None);
+        assert_eq!(grid[Position { x: 1, y: 0 }], 'b');
+    }
+
+    #[test]
+    fn finds_neighbours() {
+        let grid = get_mock_grid();
+        let neighbours: Vec<char> = grid.neighbour_indices(1).map(|i| grid[i]).collect();
+        assert_eq!(neighbours, vec!['a', 'e', 'c']);
+        assert_eq!(grid.neighbour_index(2, Direction::Right), None);
+        assert_eq!(grid.neighbour_index(3, Direction::Left), None);
+        assert_eq!(grid.neighbour_index(4, Direction::Up), Some(1));
+        assert_eq!(
+            grid.neighbours(&Position { x: 0, y: 0 }),
+            vec![Position { x: 0, y: 1 }, Position { x: 1, y: 0 }]
+        );
+    }
+
+    #[test]
+    fn slices_rows_and_columns() {
+        let grid = get_mock_grid();
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(
+            grid.column(1).unwrap().copied().collect::<Vec<_>>(),
+            vec!['b', 'e']
+        );
+        assert_eq!(grid.rows().count(), 2);
+    }
+
+    #[test]
+    fn iterates_positions_in_row_order() {
+        let grid = get_mock_grid();
+        let positions: Vec<Position> = grid.positions().collect();
+        assert_eq!(positions.len(), 6);
+        assert_eq!(positions[1], Position { x: 1, y: 0 });
+        assert_eq!(positions[3], Position { x: 0, y: 1 });
+    }
+
+    #[test]
+    fn swaps_cells() {
+        let mut grid = get_mock_grid();
+        grid.swap(&Position { x: 0, y: 0 }, &Position { x: 2, y: 1 });
+        assert_eq!(grid.to_string(), "fbc\ndea\n");
+    }
+}
@@ -1,4 +1,8 @@
+use std::collections::HashMap;
 use std::fmt;
+use std::hash::Hash;
+
+use super::grid::Grid;
 
 
 #[macro_export]
@@ -20,10 +24,7 @@ macro_rules! define_nodes {
                     $(
                         $symbol => Ok(Node::$variant),
                     )*
-                    _ => {
-                        eprintln!("Unknown symbol: {}", value);
-                        Err(())
-                    },
+                    _ => Err(()),
                 }
             }
         }
@@ -51,6 +52,88 @@ const NEIGHBOR_OFFSETS: [(i32, i32); 4] = [
     (1, 0),
 ];
 
+const MOORE_OFFSETS: [(i32, i32); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+// NOTE: opposite corners are at indices 0 and 3, and 1 and 2.
+const DIAGONAL_OFFSETS: [(i32, i32); 4] = [
+    (-1, -1),
+    (-1, 1),
+    (1, -1),
+    (1, 1),
+];
+
+/// Which cells around a position count as its neighbours. Offsets are `(dx, dy)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Neighbourhood {
+    /// The four orthogonal neighbours.
+    VonNeumann,
+    /// All eight surrounding cells.
+    Moore,
+    /// The four diagonal neighbours.
+    Diagonal,
+    Custom(&'static [(i32, i32)]),
+}
+
+impl Neighbourhood {
+    pub fn offsets(&self) -> &'static [(i32, i32)] {
+        match self {
+            Neighbourhood::VonNeumann => &NEIGHBOR_OFFSETS,
+            Neighbourhood::Moore => &MOORE_OFFSETS,
+            Neighbourhood::Diagonal => &DIAGONAL_OFFSETS,
+            Neighbourhood::Custom(offsets) => offsets,
+        }
+    }
+
+    /// Whether a cell at the offset `(dx, dy)` is a neighbour.
+    pub fn contains(&self, offset: (i32, i32)) -> bool {
+        self.offsets().contains(&offset)
+    }
+
+    /// The neighbours of a position that lie within a `width` × `height` map.
+    pub fn around(&self, position: Position, width: usize, height: usize) -> Neighbours {
+        Neighbours {
+            offsets: self.offsets().iter(),
+            position,
+            width,
+            height,
+        }
+    }
+}
+
+/// Iterator over the neighbours of a position, see [`Neighbourhood::around`].
+#[derive(Debug, Clone)]
+pub struct Neighbours {
+    offsets: std::slice::Iter<'static, (i32, i32)>,
+    position: Position,
+    width: usize,
+    height: usize,
+}
+
+impl Iterator for Neighbours {
+    type Item = Position;
+
+    fn next(&mut self) -> Option<Position> {
+        self.offsets.find_map(|(dx, dy)| {
+            let x = self.position.x.checked_add_signed(*dx)?;
+            let y = self.position.y.checked_add_signed(*dy)?;
+            ((x as usize) < self.width && (y as usize) < self.height).then_some(Position { x, y })
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.offsets.len()))
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Direction {
     Up,
@@ -78,6 +161,33 @@ impl Direction {
         }
     }
 
+    pub const fn all() -> [Direction; 4] {
+        [Direction::Up, Direction::Right, Direction::Down, Direction::Left]
+    }
+
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ();
+
+    /// Parses arrows `^>v<` and compass letters `NESW`.
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' | 'N' => Ok(Direction::Up),
+            '>' | 'E' => Ok(Direction::Right),
+            'v' | 'S' => Ok(Direction::Down),
+            '<' | 'W' => Ok(Direction::Left),
+            _ => Err(()),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -99,15 +209,12 @@ pub trait Map<T> where T: Clone + PartialEq + Eq {
     }
 
     fn neighbours(&self, position: &Position) -> Vec<Position> {
-        let Position { x, y } = position;
+        self.neighbours_in(position, Neighbourhood::VonNeumann).collect()
+    }
 
+    fn neighbours_in(&self, position: &Position, neighbourhood: Neighbourhood) -> Neighbours {
         let (width, height) = self.dimensions();
-        NEIGHBOR_OFFSETS
-            .iter()
-            .map(|(dx, dy)| (*x as i32 + dx, *y as i32 + dy))
-            .filter(|(x, y)| *x >= 0 && *y >= 0 && width as i32 > *x && height as i32 > * y)
-            .map(|(x, y)| Position { x: x as u32, y: y as u32 })
-            .collect()
+        neighbourhood.around(*position, width, height)
     }
 
 
@@ -164,7 +271,7 @@ impl<T> TryFrom<Vec<Vec<T>>> for BoxedMap<T> {
     type Error = ();
 
     fn try_from(data: Vec<Vec<T>>) -> Result<Self, Self::Error> {
-        let bounds = (data.get(0).map(|row| row.len()).unwrap_or(0), data.len());
+        let bounds = (data.first().map(|row| row.len()).unwrap_or(0), data.len());
         let data = data.into_iter().map(|row| row.into_iter().map(|item| Box::new(item)).collect()).collect();
         Ok(BoxedMap { data, bounds })
     }
@@ -199,3 +306,157 @@ impl<T: fmt::Display> fmt::Display for BoxedMap<T> {
         Ok(())
     }
 }
+
+/* -------------------------------------------------------------------------- */
+
+/// Error of [`parse_grid`]. Rows and columns are zero-based, like [`Position`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    UnknownSymbol { symbol: char, row: usize, column: usize },
+    RaggedRow { row: usize, width: usize, expected: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnknownSymbol { symbol, row, column } => {
+                write!(f, "Unknown symbol {symbol:?} at row {row}, column {column}")
+            }
+            ParseError::RaggedRow { row, width, expected } => {
+                write!(f, "Row {row} has {width} columns, expected {expected}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses every line of the input into a row of a [`Grid`].
+/// Works for any node type that implements `TryFrom<char>`, e.g. the ones generated by [`define_nodes!`].
+pub fn parse_grid<T: TryFrom<char>>(input: &str) -> Result<Grid<T>, ParseError> {
+    let mut data = Vec::with_capacity(input.len());
+    let mut width = None;
+
+    for (row, line) in input.lines().enumerate() {
+        let start = data.len();
+        for (column, symbol) in line.chars().enumerate() {
+            let node = T::try_from(symbol).map_err(|_| ParseError::UnknownSymbol { symbol, row, column })?;
+            data.push(node);
+        }
+
+        let row_width = data.len() - start;
+        match width {
+            None => width = Some(row_width),
+            Some(expected) if expected != row_width => {
+                return Err(ParseError::RaggedRow { row, width: row_width, expected });
+            }
+            Some(_) => {}
+        }
+    }
+
+    Ok(Grid::from_vec(width.unwrap_or(0), data).expect("all rows have the same width"))
+}
+
+/// Like [`parse_grid`], but also collects the positions of the given marker nodes, e.g. a start and a finish.
+pub fn parse_grid_with_markers<T>(input: &str, markers: &[T]) -> Result<(Grid<T>, Markers<T>), ParseError>
+where
+    T: TryFrom<char> + Copy + Eq + Hash,
+{
+    let grid = parse_grid(input)?;
+    let markers = Markers::find(&grid, markers);
+    Ok((grid, markers))
+}
+
+/// Positions of marker nodes in a grid, in row order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Markers<T: Eq + Hash> {
+    positions: HashMap<T, Vec<Position>>,
+}
+
+impl<T: Copy + Eq + Hash> Markers<T> {
+    pub fn find(grid: &Grid<T>, markers: &[T]) -> Self {
+        let mut positions: HashMap<T, Vec<Position>> = HashMap::new();
+        for (index, node) in grid.iter().enumerate() {
+            if markers.contains(node) {
+                positions.entry(*node).or_default().push(grid.position_of(index));
+            }
+        }
+        Self { positions }
+    }
+
+    /// The first position of a marker, `None` if it does not occur in the grid.
+    pub fn first(&self, marker: &T) -> Option<Position> {
+        self.all(marker).first().copied()
+    }
+
+    /// All positions of a marker.
+    pub fn all(&self, marker: &T) -> &[Position] {
+        self.positions.get(marker).map_or(&[], Vec::as_slice)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_grid, parse_grid_with_markers, Map, Neighbourhood, ParseError, Position};
+    use crate::utils::grid::Grid;
+
+    define_nodes!(
+        '#' => Wall,
+        '.' => Empty,
+        'S' => Start,
+    );
+
+    #[test]
+    fn parses_grids() {
+        let grid: Grid<Node> = parse_grid("#.S\n#..\n").unwrap();
+        assert_eq!(grid.dimensions(), (3, 2));
+        assert_eq!(grid.get(&Position { x: 2, y: 0 }), Some(&Node::Start));
+        assert_eq!(grid.to_string(), "#.S\n#..\n");
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        assert_eq!(
+            parse_grid::<Node>("#.\n.x\n"),
+            Err(ParseError::UnknownSymbol { symbol: 'x', row: 1, column: 1 })
+        );
+        assert_eq!(
+            parse_grid::<Node>("#.\n.\n"),
+            Err(ParseError::RaggedRow { row: 1, width: 1, expected: 2 })
+        );
+    }
+
+    #[test]
+    fn finds_markers() {
+        let (_, markers) = parse_grid_with_markers("S.#\n..S\n", &[Node::Start, Node::Wall]).unwrap();
+        assert_eq!(markers.first(&Node::Start), Some(Position { x: 0, y: 0 }));
+        assert_eq!(markers.all(&Node::Start), &[Position { x: 0, y: 0 }, Position { x: 2, y: 1 }]);
+        assert_eq!(markers.all(&Node::Wall), &[Position { x: 2, y: 0 }]);
+        assert_eq!(markers.first(&Node::Empty), None);
+    }
+
+    #[test]
+    fn finds_neighbours_in_neighbourhoods() {
+        let grid: Grid<Node> = parse_grid("...\n...\n...\n").unwrap();
+        let corner = Position { x: 0, y: 0 };
+        let center = Position { x: 1, y: 1 };
+
+        assert_eq!(grid.neighbours_in(&corner, Neighbourhood::VonNeumann).count(), 2);
+        assert_eq!(grid.neighbours_in(&corner, Neighbourhood::Moore).count(), 3);
+        assert_eq!(
+            grid.neighbours_in(&corner, Neighbourhood::Diagonal).collect::<Vec<_>>(),
+            vec![center]
+        );
+        assert_eq!(grid.neighbours_in(&center, Neighbourhood::Moore).count(), 8);
+
+        let knight = Neighbourhood::Custom(&[(1, 2), (2, 1), (-1, 2)]);
+        assert_eq!(
+            grid.neighbours_in(&corner, knight).collect::<Vec<_>>(),
+            vec![Position { x: 1, y: 2 }, Position { x: 2, y: 1 }]
+        );
+        assert!(knight.contains((2, 1)));
+        assert!(!Neighbourhood::VonNeumann.contains((1, 1)));
+    }
+}
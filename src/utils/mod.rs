@@ -1 +1,4 @@
+pub mod grid;
 pub mod map;
+pub mod search;
+pub mod vector;
@@ -0,0 +1,378 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use super::map::{Direction, Map, Position};
+
+/// Distances and shortest-path predecessors of every state a search reached.
+///
+/// Every state keeps all predecessors it can be reached from at its optimal distance, so the predecessors form a DAG
+/// of all shortest paths. This holds as long as every transition has a positive cost.
+#[derive(Debug, Clone)]
+pub struct SearchResult<S> {
+    distances: HashMap<S, u64>,
+    predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash> SearchResult<S> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    /// Records that `state` can be reached from `from` at `distance`. Returns whether this improves its distance.
+    fn relax(&mut self, state: S, from: &S, distance: u64) -> bool {
+        match self.distances.get(&state) {
+            Some(&known) if known < distance => false,
+            Some(&known) if known == distance => {
+                self.predecessors
+                    .entry(state)
+                    .or_default()
+                    .push(from.clone());
+                false
+            }
+            _ => {
+                self.distances.insert(state.clone(), distance);
+                self.predecessors.insert(state, vec![from.clone()]);
+                true
+            }
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<u64> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<S, u64> {
+        &self.distances
+    }
+
+    /// The states that precede `state` on its shortest paths. Empty for start states.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// The reached targets with the smallest distance, together with that distance.
+    pub fn nearest(&self, targets: impl IntoIterator<Item = S>) -> Option<(u64, Vec<S>)> {
+        let mut nearest: Option<(u64, Vec<S>)> = None;
+        for target in targets {
+            let Some(distance) = self.distance(&target) else {
+                continue;
+            };
+            match &mut nearest {
+                Some((best, states)) if distance == *best => states.push(target),
+                Some((best, _)) if distance > *best => {}
+                _ => nearest = Some((distance, vec![target])),
+            }
+        }
+        nearest
+    }
+
+    /// One shortest path from a start state to `target`, both included.
+    pub fn path_to(&self, target: &S) -> Option<Vec<S>> {
+        self.distances.get(target)?;
+
+        let mut path = vec![target.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// All states that lie on any shortest path to one of the `targets`, including the targets.
+    pub fn states_on_paths_to(&self, targets: &[S]) -> HashSet<S> {
+        let mut states = HashSet::new();
+        let mut stack: Vec<&S> = targets
+            .iter()
+            .filter(|target| self.distances.contains_key(target))
+            .collect();
+
+        while let Some(state) = stack.pop() {
+            if states.insert(state.clone()) {
+                stack.extend(self.predecessors(state));
+            }
+        }
+        states
+    }
+
+    /// The number of distinct shortest paths from any start state to `target`.
+    pub fn count_paths_to(&self, target: &S) -> u64 {
+        let mut states: Vec<S> = self
+            .states_on_paths_to(std::slice::from_ref(target))
+            .into_iter()
+            .collect();
+        states.sort_by_key(|state| self.distances[state]);
+
+        let mut counts: HashMap<&S, u64> = HashMap::with_capacity(states.len());
+        for state in &states {
+            let predecessors = self.predecessors(state);
+            let count = if predecessors.is_empty() {
+                1
+            } else {
+                predecessors.iter().map(|p| counts[p]).sum()
+            };
+            counts.insert(state, count);
+        }
+        counts.get(target).copied().unwrap_or(0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Breadth-first search where every transition costs 1. Explores everything reachable from `starts`.
+pub fn bfs<S, F, I>(starts: impl IntoIterator<Item = S>, mut successors: F) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let mut result = SearchResult::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if result.distances.insert(start.clone(), 0).is_none() {
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((state, distance)) = queue.pop_front() {
+        for next in successors(&state) {
+            if result.relax(next.clone(), &state, distance + 1) {
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    result
+}
+
+/// Dijkstra's algorithm over transitions `(state, cost)`. Explores everything reachable from `starts`.
+pub fn dijkstra<S, F, I>(starts: impl IntoIterator<Item = S>, successors: F) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(starts, successors, |_| 0, |_| false)
+}
+
+/// A* search over transitions `(state, cost)`, guided by a `heuristic` that estimates the remaining cost.
+///
+/// The heuristic must be consistent: it never drops by more than the cost of a transition, i.e.
+/// `heuristic(state) <= cost + heuristic(next)`, and it's 0 at the goals. Every state is then settled at its optimal
+/// distance, so the recorded predecessors only describe shortest paths. A heuristic that is admissible but not
+/// consistent can reopen settled states, which leaves non-optimal paths in the result. Checked in debug builds.
+///
+/// Stops once every goal state at the optimal distance is settled, so all shortest paths to those goals are recorded.
+/// With a heuristic of `|_| 0` this is Dijkstra's algorithm with an early exit.
+pub fn astar<S, F, I, H, G>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    H: FnMut(&S) -> u64,
+    G: FnMut(&S) -> bool,
+{
+    let mut result = SearchResult::new();
+    // NOTE: states are kept outside of the heap so they don't need to implement `Ord`.
+    let mut pending = Vec::new();
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        if result.distances.insert(start.clone(), 0).is_none() {
+            queue.push(Reverse((heuristic(&start), 0, pending.len())));
+            pending.push(Some(start));
+        }
+    }
+
+    let mut goal_distance = None;
+    while let Some(Reverse((estimate, distance, id))) = queue.pop() {
+        if goal_distance.is_some_and(|goal| estimate > goal) {
+            break;
+        }
+
+        let state = pending[id].take().expect("every state is queued once");
+        if result.distances[&state] < distance {
+            continue;
+        }
+
+        if is_goal(&state) {
+            goal_distance.get_or_insert(distance);
+            continue;
+        }
+
+        let estimate_here = estimate - distance;
+        for (next, cost) in successors(&state) {
+            let next_distance = distance + cost;
+            let estimate_next = heuristic(&next);
+            debug_assert!(
+                estimate_here <= cost + estimate_next,
+                "the heuristic of `astar` must be consistent"
+            );
+
+            if result.relax(next.clone(), &state, next_distance) {
+                queue.push(Reverse((
+                    next_distance + estimate_next,
+                    next_distance,
+                    pending.len(),
+                )));
+                pending.push(Some(next));
+            }
+        }
+    }
+
+    result
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A position on a map together with the direction it is facing, e.g. a reindeer in a maze.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pose {
+    pub position: Position,
+    pub facing: Direction,
+}
+
+impl Pose {
+    pub fn new(position: Position, facing: Direction) -> Self {
+        Self { position, facing }
+    }
+
+    /// The poses of a position in all four directions.
+    pub fn facings(position: Position) -> [Pose; 4] {
+        Direction::all().map(|facing| Pose::new(position, facing))
+    }
+
+    /// Turns by 90 degrees, clockwise unless `reverse` is set.
+    pub fn turn(self, reverse: bool) -> Self {
+        Self::new(self.position, self.facing.turn_one(reverse))
+    }
+
+    /// Moves one step forward, `None` if that leaves the map.
+    pub fn step<T: Clone + PartialEq + Eq>(self, map: &impl Map<T>) -> Option<Self> {
+        map.neighbour_in_direction(self.position, self.facing)
+            .map(|position| Self::new(position, self.facing))
+    }
+
+    /// Transitions for searching over poses: moving forward onto a `passable` cell costs `step_cost`,
+    /// turning left or right in place costs `turn_cost`.
+    pub fn successors<'a, T: Clone + PartialEq + Eq>(
+        self,
+        map: &'a impl Map<T>,
+        step_cost: u64,
+        turn_cost: u64,
+        passable: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = (Pose, u64)> + 'a {
+        let forward = self
+            .step(map)
+            .filter(|next| map.get(&next.position).is_some_and(&passable))
+            .map(|next| (next, step_cost));
+
+        [
+            forward,
+            Some((self.turn(false), turn_cost)),
+            Some((self.turn(true), turn_cost)),
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{astar, bfs, dijkstra, Pose};
+    use crate::utils::grid::Grid;
+    use crate::utils::map::{parse_grid, Direction, Map, Position};
+
+    fn get_mock_graph(state: &u32) -> Vec<(u32, u64)> {
+        // 0 -> 1 -> 3 and 0 -> 2 -> 3 both cost 2, 0 -> 3 costs 5, 3 -> 4 costs 1.
+        match state {
+            0 => vec![(1, 1), (2, 1), (3, 5)],
+            1 => vec![(3, 1)],
+            2 => vec![(3, 1)],
+            3 => vec![(4, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn finds_shortest_paths_with_dijkstra() {
+        let result = dijkstra([0], get_mock_graph);
+        assert_eq!(result.distance(&3), Some(2));
+        assert_eq!(result.distance(&4), Some(3));
+        assert_eq!(result.distance(&5), None);
+        assert_eq!(result.predecessors(&3), &[1, 2]);
+        assert_eq!(result.path_to(&4), Some(vec![0, 1, 3, 4]));
+        assert_eq!(result.count_paths_to(&4), 2);
+        assert_eq!(result.count_paths_to(&5), 0);
+        assert_eq!(result.states_on_paths_to(&[4]).len(), 5);
+    }
+
+    #[test]
+    fn stops_at_goals_with_astar() {
+        let result = astar([0], get_mock_graph, |_| 0, |state| *state == 3);
+        assert_eq!(result.distance(&3), Some(2));
+        assert_eq!(result.count_paths_to(&3), 2);
+        assert_eq!(result.distance(&4), None);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "consistent")]
+    fn rejects_inconsistent_heuristics() {
+        // overestimates the cost of 0 -> 1 by 2, but never the total cost to 4.
+        astar(
+            [0],
+            get_mock_graph,
+            |state| if *state == 0 { 3 } else { 0 },
+            |state| *state == 4,
+        );
+    }
+
+    #[test]
+    fn counts_paths_with_bfs() {
+        let grid: Grid<char> = parse_grid("...\n...\n...\n").unwrap();
+        let corner = Position { x: 2, y: 2 };
+        let result = bfs([Position { x: 0, y: 0 }], |position| {
+            let position = *position;
+            grid.neighbours(&position)
+                .into_iter()
+                .filter(move |next| next.x >= position.x && next.y >= position.y)
+        });
+        assert_eq!(result.distance(&corner), Some(4));
+        assert_eq!(result.count_paths_to(&corner), 6);
+        assert_eq!(result.path_to(&corner).map(|path| path.len()), Some(5));
+        assert_eq!(
+            result.nearest([corner, Position { x: 1, y: 0 }]),
+            Some((1, vec![Position { x: 1, y: 0 }]))
+        );
+    }
+
+    #[test]
+    fn searches_poses() {
+        let grid: Grid<char> = parse_grid("..#\n...\n").unwrap();
+        let start = Pose::new(Position { x: 0, y: 0 }, Direction::Right);
+        let finish = Position { x: 2, y: 1 };
+
+        let successors: Vec<_> = start.successors(&grid, 1, 1000, |c| *c != '#').collect();
+        assert_eq!(successors.len(), 3);
+
+        let result = dijkstra([start], |pose| {
+            pose.successors(&grid, 1, 1000, |c| *c != '#')
+        });
+        let (distance, ends) = result.nearest(Pose::facings(finish)).unwrap();
+        assert_eq!(distance, 2003);
+        assert_eq!(ends, vec![Pose::new(finish, Direction::Right)]);
+        assert_eq!(result.count_paths_to(&ends[0]), 2);
+    }
+}
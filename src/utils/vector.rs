@@ -0,0 +1,276 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use super::map::{Direction, Position};
+
+/// A signed 2D vector. `y` grows downwards, like the rows of a [`Position`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vector {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A point is a vector from the origin.
+pub type Point = Vector;
+
+impl Vector {
+    pub const ZERO: Vector = Vector::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Manhattan (taxicab) distance to another point.
+    pub fn manhattan(self, other: Vector) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Chebyshev (chessboard) distance to another point.
+    pub fn chebyshev(self, other: Vector) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Rotates the vector clockwise by 90 degrees.
+    pub fn rotate_cw(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotates the vector counter-clockwise by 90 degrees.
+    pub fn rotate_ccw(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// Rotates the vector clockwise by `turns` quarter turns. Negative turns rotate counter-clockwise.
+    pub fn rotate(self, turns: i32) -> Self {
+        match turns.rem_euclid(4) {
+            0 => self,
+            1 => self.rotate_cw(),
+            2 => -self,
+            _ => self.rotate_ccw(),
+        }
+    }
+
+    /// Wraps both coordinates into `0..width` and `0..height`, e.g. for maps that wrap around at the edges.
+    pub fn rem_euclid(self, width: i64, height: i64) -> Self {
+        Self::new(self.x.rem_euclid(width), self.y.rem_euclid(height))
+    }
+
+    /// Converts the point to a position on a `width` × `height` map, `None` if it lies outside.
+    pub fn to_position(self, width: usize, height: usize) -> Option<Position> {
+        let position = Position::try_from(self).ok()?;
+        ((position.x as usize) < width && (position.y as usize) < height).then_some(position)
+    }
+}
+
+impl From<Position> for Vector {
+    fn from(position: Position) -> Self {
+        Self::new(position.x.into(), position.y.into())
+    }
+}
+
+impl TryFrom<Vector> for Position {
+    type Error = ();
+
+    fn try_from(vector: Vector) -> Result<Self, Self::Error> {
+        Ok(Position {
+            x: vector.x.try_into().map_err(|_| ())?,
+            y: vector.y.try_into().map_err(|_| ())?,
+        })
+    }
+}
+
+impl From<(i32, i32)> for Vector {
+    fn from((x, y): (i32, i32)) -> Self {
+        Self::new(x.into(), y.into())
+    }
+}
+
+impl From<Direction> for Vector {
+    fn from(direction: Direction) -> Self {
+        Compass::from(direction).vector()
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Vector {
+    fn add_assign(&mut self, other: Vector) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Vector {
+    fn sub_assign(&mut self, other: Vector) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, factor: i64) -> Vector {
+        Vector::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+impl fmt::Display for Vector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The eight directions of a compass, clockwise from north. North points up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Compass {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Compass {
+    pub const fn all() -> [Compass; 8] {
+        [
+            Compass::North,
+            Compass::NorthEast,
+            Compass::East,
+            Compass::SouthEast,
+            Compass::South,
+            Compass::SouthWest,
+            Compass::West,
+            Compass::NorthWest,
+        ]
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn(4)
+    }
+
+    /// Turns clockwise by `steps` eighths of a full turn. Negative steps turn counter-clockwise.
+    pub fn turn(self, steps: i32) -> Self {
+        Compass::all()[(self as i32 + steps).rem_euclid(8) as usize]
+    }
+
+    /// Unit vector pointing in this direction.
+    pub fn vector(self) -> Vector {
+        match self {
+            Compass::North => Vector::new(0, -1),
+            Compass::NorthEast => Vector::new(1, -1),
+            Compass::East => Vector::new(1, 0),
+            Compass::SouthEast => Vector::new(1, 1),
+            Compass::South => Vector::new(0, 1),
+            Compass::SouthWest => Vector::new(-1, 1),
+            Compass::West => Vector::new(-1, 0),
+            Compass::NorthWest => Vector::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Compass {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Compass::North,
+            Direction::Right => Compass::East,
+            Direction::Down => Compass::South,
+            Direction::Left => Compass::West,
+        }
+    }
+}
+
+impl TryFrom<char> for Compass {
+    type Error = ();
+
+    /// Parses the four cardinal directions from `^>v<` or `NESW`. Diagonals have no single-character form.
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Direction::try_from(value).map(Compass::from)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Compass, Vector};
+    use crate::utils::map::{Direction, Position};
+
+    #[test]
+    fn does_arithmetic() {
+        let a = Vector::new(1, 2);
+        let b = Vector::new(-3, 5);
+        assert_eq!(a + b, Vector::new(-2, 7));
+        assert_eq!(a - b, Vector::new(4, -3));
+        assert_eq!(b * 2, Vector::new(-6, 10));
+        assert_eq!(-a, Vector::new(-1, -2));
+        assert_eq!(Vector::new(7, -1).rem_euclid(5, 3), Vector::new(2, 2));
+    }
+
+    #[test]
+    fn measures_distances() {
+        let a = Vector::new(1, 2);
+        let b = Vector::new(-3, 5);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+    }
+
+    #[test]
+    fn rotates() {
+        let up = Vector::from(Direction::Up);
+        assert_eq!(up.rotate_cw(), Vector::from(Direction::Right));
+        assert_eq!(up.rotate_ccw(), Vector::from(Direction::Left));
+        assert_eq!(up.rotate(2), Vector::from(Direction::Down));
+        assert_eq!(up.rotate(-1), Vector::from(Direction::Left));
+        assert_eq!(up.rotate(i32::MIN), up);
+        assert_eq!(Vector::new(2, 1).rotate(1), Vector::new(-1, 2));
+    }
+
+    #[test]
+    fn converts_to_positions() {
+        assert_eq!(
+            Vector::new(2, 1).to_position(3, 2),
+            Some(Position { x: 2, y: 1 })
+        );
+        assert_eq!(Vector::new(3, 1).to_position(3, 2), None);
+        assert_eq!(Vector::new(-1, 0).to_position(3, 2), None);
+        assert_eq!(Vector::from(Position { x: 4, y: 5 }), Vector::new(4, 5));
+    }
+
+    #[test]
+    fn turns_compass_directions() {
+        assert_eq!(Compass::NorthEast.opposite(), Compass::SouthWest);
+        assert_eq!(Compass::North.turn(-1), Compass::NorthWest);
+        assert_eq!(Compass::West.turn(3), Compass::NorthEast);
+        assert_eq!(Compass::try_from('v'), Ok(Compass::South));
+        assert_eq!(Compass::try_from('W'), Ok(Compass::West));
+        assert!(Compass::all()
+            .iter()
+            .all(|c| c.vector() + c.opposite().vector() == Vector::ZERO));
+    }
+}